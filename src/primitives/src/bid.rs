@@ -0,0 +1,138 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec::Vec;
+
+use enum_iterator::all;
+
+use crate::Strain;
+
+/// A call made by a player during the auction
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Bid {
+    Pass,
+    /// An offer to win `level` tricks beyond six in `strain`, with `level`
+    /// from 1 to 7.
+    Contract {
+        level: u8,
+        strain: Strain,
+    },
+    Double,
+    Redouble,
+}
+
+impl Bid {
+    /// Returns a contract bid of `level` in `strain`
+    pub fn contract(level: u8, strain: Strain) -> Self {
+        Bid::Contract { level, strain }
+    }
+
+    /// Returns true if this bid may legally be made after the bids in
+    /// `history`, which lists every previous bid in the auction in order.
+    ///
+    /// Passing is always legal. A contract bid must be higher than every
+    /// previous contract bid, comparing first by level and then by [Strain]. A
+    /// double is only legal when the most recent bid other than a pass was a
+    /// contract bid by the opposing side, and a redouble is only legal when it
+    /// was a double by the opposing side.
+    pub fn is_legal_after(&self, history: &[Bid]) -> bool {
+        let last_call = history.iter().enumerate().rev().find(|(_, bid)| **bid != Bid::Pass);
+        let by_opponent = |index: usize| (history.len() - index) % 2 == 1;
+        match self {
+            Bid::Pass => true,
+            Bid::Contract { level, strain } => {
+                let previous = history.iter().rev().find_map(|bid| match bid {
+                    Bid::Contract { level, strain } => Some((*level, *strain)),
+                    _ => None,
+                });
+                (1..=7).contains(level) && previous.is_none_or(|p| (*level, *strain) > p)
+            }
+            Bid::Double => {
+                matches!(last_call, Some((index, Bid::Contract { .. })) if by_opponent(index))
+            }
+            Bid::Redouble => matches!(last_call, Some((index, Bid::Double)) if by_opponent(index)),
+        }
+    }
+}
+
+/// Returns every (level, strain) contract which is strictly higher than
+/// `current`, in ascending order up to 7NT, or every contract from 1♣ upwards
+/// if `current` is None.
+pub fn bids_above(current: Option<(u8, Strain)>) -> Vec<(u8, Strain)> {
+    (1..=7)
+        .flat_map(|level| all::<Strain>().map(move |strain| (level, strain)))
+        .filter(|bid| current.is_none_or(|current| *bid > current))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascending_auction_is_legal() {
+        let auction = [
+            Bid::contract(1, Strain::Clubs),
+            Bid::Pass,
+            Bid::contract(1, Strain::Spades),
+            Bid::Double,
+            Bid::Redouble,
+            Bid::contract(2, Strain::Hearts),
+            Bid::contract(2, Strain::NoTrump),
+            Bid::Pass,
+            Bid::contract(3, Strain::Clubs),
+        ];
+        for (index, bid) in auction.iter().enumerate() {
+            assert!(bid.is_legal_after(&auction[..index]), "{bid:?} after {:?}", &auction[..index]);
+        }
+    }
+
+    #[test]
+    fn lower_contract_bid_is_illegal() {
+        let history = [Bid::contract(2, Strain::Hearts)];
+        assert!(!Bid::contract(2, Strain::Diamonds).is_legal_after(&history));
+        assert!(!Bid::contract(1, Strain::NoTrump).is_legal_after(&history));
+        assert!(!Bid::contract(2, Strain::Hearts).is_legal_after(&history));
+        assert!(!Bid::contract(8, Strain::Clubs).is_legal_after(&[]));
+    }
+
+    #[test]
+    fn doubles_only_follow_opponent_bids() {
+        assert!(!Bid::Double.is_legal_after(&[]));
+        assert!(!Bid::Double.is_legal_after(&[Bid::contract(1, Strain::Clubs), Bid::Pass]));
+        assert!(Bid::Double.is_legal_after(&[
+            Bid::contract(1, Strain::Clubs),
+            Bid::Pass,
+            Bid::Pass
+        ]));
+        assert!(!Bid::Redouble.is_legal_after(&[Bid::contract(1, Strain::Clubs)]));
+        assert!(!Bid::Redouble.is_legal_after(&[
+            Bid::contract(1, Strain::Clubs),
+            Bid::Double,
+            Bid::Pass
+        ]));
+    }
+
+    #[test]
+    fn bids_above_range() {
+        assert!(bids_above(Some((7, Strain::NoTrump))).is_empty());
+        let all_bids = bids_above(None);
+        assert_eq!(all_bids.len(), 35);
+        assert_eq!(all_bids.first(), Some(&(1, Strain::Clubs)));
+        assert_eq!(all_bids.last(), Some(&(7, Strain::NoTrump)));
+        assert_eq!(bids_above(Some((6, Strain::NoTrump))).first(), Some(&(7, Strain::Clubs)));
+        assert_eq!(bids_above(Some((1, Strain::Spades))).len(), 31);
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Relabeling of suits so that deals which differ only by a permutation of
+//! suits share a single representative, which is useful as a transposition
+//! table key for no-trump play.
+
+use core::cmp::Reverse;
+use std::collections::HashMap;
+
+use enum_iterator::{all, reverse_all};
+
+use crate::hand::Hand;
+use crate::{Card, HandIdentifier, Suit};
+
+/// Returns a canonical relabeling of the suits in a deal, where the suit at
+/// index [Suit::to_index] of the result is the suit which that suit becomes.
+///
+/// Suits are ranked by their holdings, comparing the ranks held by each
+/// position in turn starting from North, and the strongest holding is mapped to
+/// Spades, the next to Hearts, and so on. Two deals which differ only by a
+/// permutation of suits therefore produce the same [canonicalize_deal] result.
+pub fn canonical_suit_mapping(hands: &HashMap<HandIdentifier, Hand>) -> [Suit; 4] {
+    let mut suits = all::<Suit>().collect::<Vec<_>>();
+    suits.sort_by_key(|suit| Reverse(holding_key(hands, *suit)));
+
+    let mut result = [Suit::Clubs; 4];
+    for (suit, target) in suits.into_iter().zip(reverse_all::<Suit>()) {
+        result[suit.to_index() as usize] = target;
+    }
+    result
+}
+
+/// Applies [canonical_suit_mapping] to every card in a deal.
+pub fn canonicalize_deal(hands: &HashMap<HandIdentifier, Hand>) -> HashMap<HandIdentifier, Hand> {
+    let mapping = canonical_suit_mapping(hands);
+    hands
+        .iter()
+        .map(|(seat, hand)| {
+            let cards = hand
+                .iter()
+                .map(|card| Card::new(mapping[card.suit.to_index() as usize], card.rank));
+            (*seat, cards.collect())
+        })
+        .collect()
+}
+
+/// Returns a bitmask of the ranks each position holds in `suit`, in
+/// [HandIdentifier] order. Missing positions are treated as holding no cards.
+fn holding_key(hands: &HashMap<HandIdentifier, Hand>, suit: Suit) -> [u16; 4] {
+    let mut result = [0; 4];
+    for (i, seat) in all::<HandIdentifier>().enumerate() {
+        if let Some(hand) = hands.get(&seat) {
+            for card in hand.iter().filter(|card| card.suit == suit) {
+                result[i] |= 1 << card.rank.to_index();
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::deck;
+
+    fn relabel(
+        hands: &HashMap<HandIdentifier, Hand>,
+        mapping: [Suit; 4],
+    ) -> HashMap<HandIdentifier, Hand> {
+        hands
+            .iter()
+            .map(|(seat, hand)| {
+                let cards = hand
+                    .iter()
+                    .map(|card| Card::new(mapping[card.suit.to_index() as usize], card.rank));
+                (*seat, cards.collect())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn suit_permuted_deals_share_canonical_form() {
+        let mut rng = StdRng::seed_from_u64(60);
+        for _ in 0..20 {
+            let hands = deck::deal_hands(&mut rng).to_map();
+            let permuted =
+                relabel(&hands, [Suit::Hearts, Suit::Clubs, Suit::Spades, Suit::Diamonds]);
+            assert_ne!(hands, permuted);
+            assert_eq!(canonicalize_deal(&hands), canonicalize_deal(&permuted));
+        }
+    }
+
+    #[test]
+    fn canonical_mapping_is_a_permutation() {
+        let hands = deck::deal_hands(&mut StdRng::seed_from_u64(6)).to_map();
+        let mut mapping = canonical_suit_mapping(&hands);
+        let canonical = canonicalize_deal(&hands);
+        assert_eq!(canonical_suit_mapping(&canonical), Suit::ALL);
+        mapping.sort();
+        assert_eq!(mapping, Suit::ALL);
+    }
+}
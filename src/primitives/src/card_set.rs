@@ -0,0 +1,420 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use rand::Rng;
+
+use crate::{Card, Rank, Suit};
+
+/// A set of playing cards, stored as a bitmask where each bit position is the
+/// [Card::to_index] of a card in the set.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Default)]
+pub struct CardSet(u64);
+
+impl CardSet {
+    /// Returns an empty set
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    /// Returns a set containing all 52 cards
+    pub fn full() -> Self {
+        Self((1 << 52) - 1)
+    }
+
+    /// Returns the set whose bitmask is `bits`. Bits above the 52 card
+    /// positions are ignored.
+    pub fn from_bits(bits: u64) -> Self {
+        Self(bits & Self::full().0)
+    }
+
+    /// Returns the bitmask for this set, with bit [Card::to_index] set for
+    /// each card in the set
+    pub fn to_bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns a set containing all 13 cards of `suit`
+    pub fn suit_mask(suit: Suit) -> Self {
+        Self(RankSet::ALL_BITS << (suit.to_index() * 13))
+    }
+
+    /// Adds a card to this set. Returns true if the card was not already
+    /// present.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 |= Self::bit(card);
+        !present
+    }
+
+    /// Removes a card from this set. Returns true if the card was present.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let present = self.contains(card);
+        self.0 &= !Self::bit(card);
+        present
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.0 & Self::bit(card) != 0
+    }
+
+    /// Returns the cards which are in either this set or `other`
+    pub fn union(&self, other: CardSet) -> CardSet {
+        Self(self.0 | other.0)
+    }
+
+    /// Returns the cards which are in both this set and `other`
+    pub fn intersection(&self, other: CardSet) -> CardSet {
+        Self(self.0 & other.0)
+    }
+
+    /// Returns the cards which are in this set but not in `other`
+    pub fn difference(&self, other: CardSet) -> CardSet {
+        Self(self.0 & !other.0)
+    }
+
+    /// Number of cards in this set
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the cards in this set which belong to `suit`
+    pub fn cards_of_suit(&self, suit: Suit) -> CardSet {
+        self.intersection(Self::suit_mask(suit))
+    }
+
+    /// Returns the ranks of the cards in this set which belong to `suit`
+    pub fn ranks_in(&self, suit: Suit) -> RankSet {
+        RankSet(((self.0 >> (suit.to_index() * 13)) & RankSet::ALL_BITS) as u16)
+    }
+
+    /// Iterates over the cards in this set in ascending [Card] order.
+    pub fn iter(&self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// Iterates over the cards in this set in descending [Card] order.
+    pub fn iter_desc(&self) -> impl Iterator<Item = Card> {
+        self.iter().rev()
+    }
+
+    fn bit(card: Card) -> u64 {
+        1 << card.to_index()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        let mut result = Self::new();
+        for card in iter {
+            result.insert(card);
+        }
+        result
+    }
+}
+
+impl IntoIterator for CardSet {
+    type IntoIter = CardSetIter;
+    type Item = Card;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CardSet {
+    type IntoIter = CardSetIter;
+    type Item = Card;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the cards in a [CardSet] in ascending order.
+#[derive(Debug, Clone)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let card = Card::from_index(self.0.trailing_zeros() as u8);
+        self.0 &= self.0 - 1;
+        card
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CardSetIter {
+    fn next_back(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = 63 - self.0.leading_zeros();
+        self.0 &= !(1 << index);
+        Card::from_index(index as u8)
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+/// A set of ranks within a single suit, stored as a bitmask where each bit
+/// position is the [Rank::to_index] of a rank in the set.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Default)]
+pub struct RankSet(u16);
+
+impl RankSet {
+    const ALL_BITS: u64 = (1 << 13) - 1;
+
+    /// Returns an empty set
+    pub fn new() -> Self {
+        Self(0)
+    }
+
+    /// Adds a rank to this set. Returns true if the rank was not already
+    /// present.
+    pub fn insert(&mut self, rank: Rank) -> bool {
+        let present = self.contains(rank);
+        self.0 |= 1 << rank.to_index();
+        !present
+    }
+
+    pub fn contains(&self, rank: Rank) -> bool {
+        self.0 & (1 << rank.to_index()) != 0
+    }
+
+    /// Number of ranks in this set
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Iterates over the ranks in this set in ascending order
+    pub fn iter(&self) -> RankSetIter {
+        RankSetIter(self.0)
+    }
+}
+
+impl FromIterator<Rank> for RankSet {
+    fn from_iter<T: IntoIterator<Item = Rank>>(iter: T) -> Self {
+        let mut result = Self::new();
+        for rank in iter {
+            result.insert(rank);
+        }
+        result
+    }
+}
+
+impl IntoIterator for RankSet {
+    type IntoIter = RankSetIter;
+    type Item = Rank;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the ranks in a [RankSet] in ascending order.
+#[derive(Debug, Clone)]
+pub struct RankSetIter(u16);
+
+impl Iterator for RankSetIter {
+    type Item = Rank;
+
+    fn next(&mut self) -> Option<Rank> {
+        if self.0 == 0 {
+            return None;
+        }
+        let rank = Rank::from_index(self.0.trailing_zeros() as u8);
+        self.0 &= self.0 - 1;
+        rank
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for RankSetIter {}
+
+/// Picks a card uniformly at random from `set`, or returns None if the set is
+/// empty.
+pub fn sample_from(set: &CardSet, rng: &mut impl Rng) -> Option<Card> {
+    if set.is_empty() {
+        return None;
+    }
+    set.iter().nth(rng.gen_range(0..set.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use std::collections::HashSet;
+
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    fn random_cards(rng: &mut StdRng) -> Vec<Card> {
+        (0..rng.gen_range(0..40)).map(|_| Card::from_index(rng.gen_range(0..52)).unwrap()).collect()
+    }
+
+    #[test]
+    fn full_contains_every_card() {
+        let full = CardSet::full();
+        assert_eq!(full.len(), 52);
+        assert!(Card::all().all(|card| full.contains(card)));
+        assert!(CardSet::new().is_empty());
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let card = Card::new(Suit::Hearts, Rank::Queen);
+        let mut set = CardSet::new();
+        assert!(set.insert(card));
+        assert!(!set.insert(card));
+        assert!(set.contains(card));
+        assert!(set.remove(card));
+        assert!(!set.remove(card));
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn len_matches_iter_count() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..100 {
+            let set = random_cards(&mut rng).into_iter().collect::<CardSet>();
+            assert_eq!(set.len(), set.iter().count());
+            assert!(set.iter().collect::<Vec<_>>().windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn set_operations_agree_with_hash_set() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for _ in 0..100 {
+            let (a, b) = (random_cards(&mut rng), random_cards(&mut rng));
+            let (set_a, set_b) =
+                (a.iter().copied().collect::<CardSet>(), b.iter().copied().collect());
+            let (hash_a, hash_b) = (a.into_iter().collect::<HashSet<_>>(), b.into_iter().collect());
+
+            assert_eq!(set_a.len(), hash_a.len());
+            let check = |set: CardSet, expected: HashSet<Card>| {
+                assert_eq!(set.len(), expected.len());
+                assert_eq!(set.iter().collect::<HashSet<_>>(), expected);
+            };
+            check(set_a.union(set_b), hash_a.union(&hash_b).copied().collect());
+            check(set_a.intersection(set_b), hash_a.intersection(&hash_b).copied().collect());
+            check(set_a.difference(set_b), hash_a.difference(&hash_b).copied().collect());
+        }
+    }
+
+    #[test]
+    fn rank_set_insert_and_iterate() {
+        let ranks = [Rank::King, Rank::Two, Rank::Ten].into_iter().collect::<RankSet>();
+        assert_eq!(ranks.len(), 3);
+        assert!(ranks.contains(Rank::Ten));
+        assert!(!ranks.contains(Rank::Ace));
+        assert_eq!(ranks.iter().collect::<Vec<_>>(), [Rank::Two, Rank::Ten, Rank::King]);
+
+        let mut ranks = RankSet::new();
+        assert!(ranks.is_empty());
+        assert!(ranks.insert(Rank::Ace));
+        assert!(!ranks.insert(Rank::Ace));
+    }
+
+    #[test]
+    fn ranks_in_extracts_one_suit() {
+        let set =
+            [(Suit::Hearts, Rank::Ace), (Suit::Hearts, Rank::Four), (Suit::Spades, Rank::Queen)]
+                .into_iter()
+                .map(Card::from)
+                .collect::<CardSet>();
+        assert_eq!(set.ranks_in(Suit::Hearts).iter().collect::<Vec<_>>(), [Rank::Four, Rank::Ace]);
+        assert_eq!(set.ranks_in(Suit::Spades).iter().collect::<Vec<_>>(), [Rank::Queen]);
+        assert!(set.ranks_in(Suit::Clubs).is_empty());
+    }
+
+    #[test]
+    fn suit_mask_and_cards_of_suit() {
+        let hearts = CardSet::suit_mask(Suit::Hearts);
+        assert_eq!(hearts.len(), 13);
+        assert!(hearts.iter().all(|card| card.suit == Suit::Hearts));
+
+        let hand =
+            [(Suit::Hearts, Rank::Ace), (Suit::Hearts, Rank::Two), (Suit::Clubs, Rank::King)]
+                .into_iter()
+                .map(Card::from)
+                .collect::<CardSet>();
+        let expected = [Card::new(Suit::Hearts, Rank::Two), Card::new(Suit::Hearts, Rank::Ace)];
+        assert_eq!(hand.intersection(hearts).iter().collect::<Vec<_>>(), expected);
+        assert_eq!(hand.cards_of_suit(Suit::Hearts), hand.intersection(hearts));
+        assert_eq!(CardSet::full().difference(hand).cards_of_suit(Suit::Hearts).len(), 11);
+    }
+
+    #[test]
+    fn sample_from_single_card_set() {
+        let card = Card::new(Suit::Clubs, Rank::Nine);
+        let mut set = CardSet::new();
+        set.insert(card);
+        let mut rng = StdRng::seed_from_u64(63);
+        for _ in 0..20 {
+            assert_eq!(sample_from(&set, &mut rng), Some(card));
+        }
+    }
+
+    #[test]
+    fn sample_from_empty_set_is_none() {
+        assert_eq!(sample_from(&CardSet::new(), &mut StdRng::seed_from_u64(63)), None);
+    }
+
+    #[test]
+    fn sample_from_reaches_every_card() {
+        let set = CardSet::suit_mask(Suit::Diamonds);
+        let mut rng = StdRng::seed_from_u64(63);
+        let drawn = (0..500).map(|_| sample_from(&set, &mut rng).unwrap()).collect::<HashSet<_>>();
+        assert_eq!(drawn, set.iter().collect());
+    }
+
+    #[test]
+    fn iter_desc_reverses_iter() {
+        let mut rng = StdRng::seed_from_u64(84);
+        for _ in 0..50 {
+            let set = random_cards(&mut rng).into_iter().collect::<CardSet>();
+            let mut ascending = set.iter().collect::<Vec<_>>();
+            let descending = set.iter_desc().collect::<Vec<_>>();
+            assert_eq!(descending.len(), set.len());
+            ascending.reverse();
+            assert_eq!(descending, ascending);
+        }
+        assert_eq!(CardSet::full().iter_desc().next(), Some(Card::new(Suit::Spades, Rank::Ace)));
+        assert_eq!(CardSet::new().iter_desc().next(), None);
+    }
+}
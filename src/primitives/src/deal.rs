@@ -0,0 +1,333 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::{fmt, mem};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use enum_iterator::all;
+
+use crate::card_set::CardSet;
+use crate::hand::{Hand, HAND_SIZE};
+use crate::trick::{Trick, TrickError};
+use crate::{Card, HandIdentifier};
+
+/// The hands held by all four positions in a round, along with the trick
+/// currently being played.
+///
+/// Deals are checked to be complete when they are constructed, see
+/// [DealError], after which cards are removed from the hands as they are
+/// played.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Deal {
+    /// Hands indexed by [HandIdentifier::ordinal]
+    hands: [Hand; 4],
+    current_trick: Trick,
+}
+
+impl Deal {
+    /// Creates a deal from the hands held by North, East, South, and West, in
+    /// that order.
+    pub fn new(hands: [Hand; 4]) -> Result<Self, DealError> {
+        validate_hands(&hands.each_ref())?;
+        Ok(Self { hands, current_trick: Trick::new() })
+    }
+
+    /// Returns the hand held by `seat`
+    pub fn hand(&self, seat: HandIdentifier) -> &Hand {
+        &self.hands[seat.ordinal() as usize]
+    }
+
+    /// Returns a mutable reference to the hand held by `seat`
+    pub fn hand_mut(&mut self, seat: HandIdentifier) -> &mut Hand {
+        &mut self.hands[seat.ordinal() as usize]
+    }
+
+    /// Iterates over each position and its hand, from North to West
+    pub fn hands(&self) -> impl Iterator<Item = (HandIdentifier, &Hand)> {
+        all::<HandIdentifier>().zip(&self.hands)
+    }
+
+    /// Returns the cards played so far to the trick in progress
+    pub fn current_trick(&self) -> &Trick {
+        &self.current_trick
+    }
+
+    /// Plays `card` from the hand held by `seat` to the current trick.
+    ///
+    /// When this is the fourth card of the trick, the completed trick is
+    /// returned and a new, empty trick is started. Returns an error without
+    /// modifying the deal if `seat` does not hold `card` or has already played
+    /// to the current trick.
+    pub fn play_card(
+        &mut self,
+        seat: HandIdentifier,
+        card: Card,
+    ) -> Result<Option<Trick>, PlayError> {
+        if !self.hand(seat).contains(card) {
+            return Err(PlayError::NotInHand(card));
+        }
+        self.current_trick.play(seat, card).map_err(PlayError::InvalidTrick)?;
+        self.hand_mut(seat).remove(card);
+        Ok(self.current_trick.is_complete().then(|| mem::take(&mut self.current_trick)))
+    }
+
+    /// Returns the hands in this deal keyed by position, as used by APIs which
+    /// also accept partial deals
+    #[cfg(feature = "std")]
+    pub fn to_map(&self) -> HashMap<HandIdentifier, Hand> {
+        self.hands().map(|(seat, hand)| (seat, hand.clone())).collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl TryFrom<HashMap<HandIdentifier, Hand>> for Deal {
+    type Error = DealError;
+
+    fn try_from(mut hands: HashMap<HandIdentifier, Hand>) -> Result<Self, Self::Error> {
+        validate_deal(&hands)?;
+        Ok(Self {
+            hands: HandIdentifier::ALL.map(|seat| hands.remove(&seat).expect("Deal was validated")),
+            current_trick: Trick::new(),
+        })
+    }
+}
+
+/// Checks that a deal is complete: every position holds [HAND_SIZE] cards and
+/// no card is held by more than one position, so that each of the 52 cards is
+/// held by exactly one position.
+#[cfg(feature = "std")]
+pub fn validate_deal(hands: &HashMap<HandIdentifier, Hand>) -> Result<(), DealError> {
+    let ordered = HandIdentifier::ALL
+        .map(|seat| hands.get(&seat).ok_or(DealError::MissingSeat(seat)))
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>()?;
+    validate_hands(&ordered)
+}
+
+/// Checks that the hands held by North, East, South, and West, in that order,
+/// form a complete deal.
+fn validate_hands(hands: &[&Hand]) -> Result<(), DealError> {
+    let mut seen = CardSet::new();
+    for card in hands.iter().flat_map(|hand| hand.iter()) {
+        if !seen.insert(card) {
+            return Err(DealError::DuplicateCard(card));
+        }
+    }
+
+    // Hands hold at most HAND_SIZE cards, so once every hand is full and no
+    // card is repeated, all 52 cards must have been dealt.
+    for (seat, hand) in all::<HandIdentifier>().zip(hands) {
+        if hand.len() != HAND_SIZE {
+            return Err(DealError::WrongSize { seat, size: hand.len() });
+        }
+    }
+    Ok(())
+}
+
+/// Error describing why a deal is not complete
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum DealError {
+    /// No hand was provided for this position
+    MissingSeat(HandIdentifier),
+    /// This position holds `size` cards instead of [HAND_SIZE]
+    WrongSize { seat: HandIdentifier, size: usize },
+    /// This card is held by more than one position
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for DealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DealError::MissingSeat(seat) => write!(f, "Missing hand for {seat}"),
+            DealError::WrongSize { seat, size } => {
+                write!(f, "{seat} holds {size} cards instead of {HAND_SIZE}")
+            }
+            DealError::DuplicateCard(card) => write!(f, "Card {card} was dealt more than once"),
+        }
+    }
+}
+
+impl core::error::Error for DealError {}
+
+/// Error returned by [Deal::play_card]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum PlayError {
+    /// The position does not hold this card
+    NotInHand(Card),
+    /// The card could not be added to the current trick
+    InvalidTrick(TrickError),
+}
+
+impl fmt::Display for PlayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlayError::NotInHand(card) => write!(f, "Card {card} is not in this hand"),
+            PlayError::InvalidTrick(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl core::error::Error for PlayError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            PlayError::InvalidTrick(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::deck;
+    use crate::{Rank, Suit};
+
+    /// Deals every `step`th card of a sorted deck to each position in turn
+    fn dealt_in_rotation(step: usize) -> [Hand; 4] {
+        HandIdentifier::ALL
+            .map(|seat| Card::all().skip(seat.ordinal() as usize).step_by(step).collect())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_deal_accepts_complete_deal() {
+        let hands = deck::deal_from_permutation(0).to_map();
+        assert_eq!(validate_deal(&hands), Ok(()));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_deal_reports_missing_seat() {
+        let mut hands = deck::deal_from_permutation(0).to_map();
+        hands.remove(&HandIdentifier::South);
+        assert_eq!(validate_deal(&hands), Err(DealError::MissingSeat(HandIdentifier::South)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_deal_reports_wrong_size() {
+        let mut hands = deck::deal_from_permutation(0).to_map();
+        let card = hands[&HandIdentifier::East].cards()[0];
+        hands.get_mut(&HandIdentifier::East).unwrap().remove(card);
+        assert_eq!(
+            validate_deal(&hands),
+            Err(DealError::WrongSize { seat: HandIdentifier::East, size: 12 })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn validate_deal_reports_duplicate_card() {
+        let mut hands = deck::deal_from_permutation(0).to_map();
+        let two_of_clubs = Card::new(Suit::Clubs, Rank::Two);
+        assert!(hands[&HandIdentifier::North].contains(two_of_clubs));
+        let west = hands.get_mut(&HandIdentifier::West).unwrap();
+        let card = west.cards()[0];
+        west.remove(card);
+        west.try_insert(two_of_clubs).unwrap();
+        assert_eq!(validate_deal(&hands), Err(DealError::DuplicateCard(two_of_clubs)));
+    }
+
+    #[test]
+    fn deal_from_four_hands() {
+        let hands = dealt_in_rotation(4);
+        let deal = Deal::new(hands.clone()).unwrap();
+        for (seat, hand) in all::<HandIdentifier>().zip(&hands) {
+            assert_eq!(deal.hand(seat), hand);
+        }
+        assert_eq!(deal.hands().map(|(seat, _)| seat).collect::<Vec<_>>(), HandIdentifier::ALL);
+    }
+
+    #[test]
+    fn overlapping_hands_are_rejected() {
+        let mut hands = dealt_in_rotation(4);
+        let ace_of_spades = Card::new(Suit::Spades, Rank::Ace);
+        assert!(hands[3].remove(ace_of_spades));
+        let two_of_clubs = Card::new(Suit::Clubs, Rank::Two);
+        hands[3].try_insert(two_of_clubs).unwrap();
+        assert_eq!(Deal::new(hands), Err(DealError::DuplicateCard(two_of_clubs)));
+    }
+
+    #[test]
+    fn short_hands_are_rejected() {
+        let hands = dealt_in_rotation(8);
+        assert_eq!(
+            Deal::new(hands),
+            Err(DealError::WrongSize { seat: HandIdentifier::North, size: 7 })
+        );
+    }
+
+    #[test]
+    fn hand_mut_updates_deal() {
+        let mut deal = Deal::new(dealt_in_rotation(4)).unwrap();
+        let two_of_clubs = Card::new(Suit::Clubs, Rank::Two);
+        assert!(deal.hand_mut(HandIdentifier::North).remove(two_of_clubs));
+        assert_eq!(deal.hand(HandIdentifier::North).len(), 12);
+    }
+
+    #[test]
+    fn play_card_moves_card_into_trick() {
+        let mut deal = Deal::new(dealt_in_rotation(4)).unwrap();
+        let two_of_clubs = Card::new(Suit::Clubs, Rank::Two);
+        assert_eq!(deal.play_card(HandIdentifier::North, two_of_clubs), Ok(None));
+        assert!(!deal.hand(HandIdentifier::North).contains(two_of_clubs));
+        assert_eq!(deal.hand(HandIdentifier::North).len(), 12);
+        assert_eq!(deal.current_trick().plays(), [(HandIdentifier::North, two_of_clubs)]);
+    }
+
+    #[test]
+    fn illegal_play_leaves_deal_untouched() {
+        let mut deal = Deal::new(dealt_in_rotation(4)).unwrap();
+        let before = deal.clone();
+        let three_of_clubs = Card::new(Suit::Clubs, Rank::Three);
+        assert_eq!(
+            deal.play_card(HandIdentifier::North, three_of_clubs),
+            Err(PlayError::NotInHand(three_of_clubs))
+        );
+        assert_eq!(deal, before);
+
+        deal.play_card(HandIdentifier::North, Card::new(Suit::Clubs, Rank::Two)).unwrap();
+        let before = deal.clone();
+        assert_eq!(
+            deal.play_card(HandIdentifier::North, Card::new(Suit::Clubs, Rank::Six)),
+            Err(PlayError::InvalidTrick(TrickError::DuplicateSeat))
+        );
+        assert_eq!(deal, before);
+    }
+
+    #[test]
+    fn play_card_returns_completed_trick() {
+        let mut deal = Deal::new(dealt_in_rotation(4)).unwrap();
+        let plays = HandIdentifier::ALL
+            .into_iter()
+            .zip([Rank::Two, Rank::Three, Rank::Four, Rank::Five])
+            .map(|(seat, rank)| (seat, Card::new(Suit::Clubs, rank)))
+            .collect::<Vec<_>>();
+        for &(seat, card) in &plays[..3] {
+            assert_eq!(deal.play_card(seat, card), Ok(None));
+        }
+        let (seat, card) = plays[3];
+        let completed = deal.play_card(seat, card).unwrap().expect("Trick should be complete");
+        assert_eq!(completed.plays(), plays);
+        assert!(deal.current_trick().plays().is_empty());
+
+        let six_of_clubs = Card::new(Suit::Clubs, Rank::Six);
+        assert_eq!(deal.play_card(HandIdentifier::North, six_of_clubs), Ok(None));
+        assert_eq!(deal.current_trick().plays(), [(HandIdentifier::North, six_of_clubs)]);
+    }
+}
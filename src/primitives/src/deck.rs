@@ -0,0 +1,432 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, RngCore, SeedableRng};
+
+use crate::deal::Deal;
+#[cfg(feature = "std")]
+use crate::hand::Hand;
+use crate::hand::HAND_SIZE;
+use crate::{Card, HandIdentifier};
+
+/// An ordered pile of playing cards which can be shuffled and dealt from.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct Deck {
+    /// Cards in this deck, the last element is the top of the deck.
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// Returns a deck containing each of the 52 standard playing cards exactly
+    /// once, in sorted order.
+    pub fn standard() -> Self {
+        Self { cards: Card::all().collect() }
+    }
+
+    /// Randomly reorders the cards in this deck.
+    ///
+    /// The random number generator is supplied by the caller so that shuffles
+    /// can be made reproducible by seeding it.
+    pub fn shuffle(&mut self, rng: &mut impl Rng) {
+        self.cards.shuffle(rng);
+    }
+
+    /// Reorders the cards in this deck using a custom [Shuffler].
+    pub fn shuffle_with(&mut self, shuffler: &mut impl Shuffler) {
+        shuffler.shuffle(&mut self.cards);
+    }
+
+    /// Removes `n` cards from the top of this deck and returns them in the
+    /// order they were dealt.
+    ///
+    /// Returns fewer than `n` cards if the deck runs out.
+    pub fn deal(&mut self, n: usize) -> Vec<Card> {
+        let remaining = self.cards.len().saturating_sub(n);
+        let mut result = self.cards.split_off(remaining);
+        result.reverse();
+        result
+    }
+}
+
+/// An algorithm for reordering a sequence of cards, see [Deck::shuffle_with].
+pub trait Shuffler {
+    /// Reorders `cards` in place
+    fn shuffle(&mut self, cards: &mut [Card]);
+}
+
+/// Uniformly random [Shuffler] using the Fisher-Yates algorithm with the
+/// wrapped random number generator.
+#[derive(Debug, Clone)]
+pub struct FisherYates<R: Rng>(pub R);
+
+impl<R: Rng> Shuffler for FisherYates<R> {
+    fn shuffle(&mut self, cards: &mut [Card]) {
+        cards.shuffle(&mut self.0);
+    }
+}
+
+/// Shuffles a standard deck and deals [HAND_SIZE] cards to each of the four
+/// positions.
+pub fn deal_hands(rng: &mut impl Rng) -> Deal {
+    let mut deck = Deck::standard();
+    deck.shuffle(rng);
+    Deal::new(HandIdentifier::ALL.map(|_| deck.deal(HAND_SIZE).into_iter().collect()))
+        .expect("Standard deck produced an invalid deal")
+}
+
+/// Number of distinct deals of a standard deck, i.e. the number of ways to
+/// split the 52 cards into four hands of [HAND_SIZE] cards.
+pub const DEAL_COUNT: u128 = 53_644_737_765_488_792_839_237_440_000;
+
+/// Deterministically deals the hands identified by `index`, reduced modulo
+/// [DEAL_COUNT], so that every possible deal has exactly one index.
+///
+/// The index is decoded in a mixed radix whose digits give the rank of each
+/// hand among the combinations of [HAND_SIZE] cards still undealt, with
+/// North's hand as the least significant digit. Hands are dealt in blocks
+/// of [HAND_SIZE] cards to North, East, South, and West in that order, like
+/// [deal_hands]. Index 0 gives North every Club, East every Diamond, South
+/// every Heart, and West every Spade.
+pub fn deal_from_permutation(index: u128) -> Deal {
+    let mut index = index % DEAL_COUNT;
+    let mut undealt = Card::all().collect::<Vec<_>>();
+    let hands = HandIdentifier::ALL.map(|_| {
+        let combinations = binomial(undealt.len(), HAND_SIZE);
+        let hand = take_combination(&mut undealt, index % combinations);
+        index /= combinations;
+        hand.into_iter().collect()
+    });
+    Deal::new(hands).expect("Deal index produced an invalid deal")
+}
+
+/// Removes the combination of [HAND_SIZE] cards at position `rank` in the
+/// lexicographic order of such combinations from `cards`, and returns it.
+fn take_combination(cards: &mut Vec<Card>, mut rank: u128) -> Vec<Card> {
+    let mut result = vec![];
+    let mut i = 0;
+    while result.len() < HAND_SIZE {
+        let including = binomial(cards.len() - i - 1, HAND_SIZE - result.len() - 1);
+        if rank < including {
+            result.push(cards.remove(i));
+        } else {
+            rank -= including;
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Returns the number of ways to choose `k` items from `n`
+fn binomial(n: usize, k: usize) -> u128 {
+    (0..k as u128).fold(1, |result, i| result * (n as u128 - i) / (i + 1))
+}
+
+/// Deals random hands until `seat` receives a hand with between `min_hcp` and
+/// `max_hcp` high card points inclusive, as counted by
+/// [crate::hand::Hand::high_card_points].
+///
+/// Returns None if no matching deal was found after `attempts` deals.
+pub fn deal_with_constraint(
+    rng: &mut impl Rng,
+    seat: HandIdentifier,
+    min_hcp: u8,
+    max_hcp: u8,
+    attempts: usize,
+) -> Option<Deal> {
+    (0..attempts)
+        .map(|_| deal_hands(rng))
+        .find(|deal| (min_hcp..=max_hcp).contains(&deal.hand(seat).high_card_points()))
+}
+
+/// Returns the positions of a deal in the fixed order North, East, South,
+/// West, for producing deterministic output from a deal.
+pub fn deal_seats_in_order() -> [HandIdentifier; 4] {
+    HandIdentifier::ALL
+}
+
+/// Lists the hands in a deal in [deal_seats_in_order] order, omitting missing
+/// positions.
+#[cfg(feature = "std")]
+pub fn deal_to_ordered_vec(hands: &HashMap<HandIdentifier, Hand>) -> Vec<(HandIdentifier, Hand)> {
+    deal_seats_in_order()
+        .into_iter()
+        .filter_map(|seat| hands.get(&seat).map(|hand| (seat, hand.clone())))
+        .collect()
+}
+
+/// Random number generator for game setup which is always constructed from an
+/// explicit seed, so that games can be reproduced for debugging.
+///
+/// The same seed produces the same sequence of values for a given version of
+/// the `rand` crate.
+#[derive(Debug, Clone)]
+pub struct GameRng(StdRng);
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::collections::BTreeSet;
+
+    use super::*;
+    use crate::card_set::CardSet;
+    use crate::{Rank, Suit};
+
+    #[test]
+    fn standard_deck_contains_each_card_once() {
+        let deck = Deck::standard();
+        assert_eq!(deck.cards.len(), 52);
+        assert_eq!(deck.cards.iter().copied().collect::<CardSet>(), CardSet::full());
+    }
+
+    #[test]
+    fn shuffle_is_reproducible_from_seed() {
+        let mut first = Deck::standard();
+        let mut second = Deck::standard();
+        first.shuffle(&mut StdRng::seed_from_u64(7));
+        second.shuffle(&mut StdRng::seed_from_u64(7));
+        assert_eq!(first, second);
+        assert_ne!(first, Deck::standard());
+    }
+
+    #[test]
+    fn deal_takes_cards_from_top() {
+        let mut deck = Deck::standard();
+        let dealt = deck.deal(3);
+        let top_spades =
+            [Rank::Ace, Rank::King, Rank::Queen].map(|rank| Card::new(Suit::Spades, rank));
+        assert_eq!(dealt, top_spades);
+        assert_eq!(deck.cards.len(), 49);
+        assert_eq!(deck.deal(100).len(), 49);
+        assert!(deck.deal(1).is_empty());
+    }
+
+    #[test]
+    fn deal_hands_uses_each_card_once() {
+        let deal = deal_hands(&mut StdRng::seed_from_u64(3));
+        let mut seen = CardSet::new();
+        for (_, hand) in deal.hands() {
+            assert_eq!(hand.len(), HAND_SIZE);
+            for card in hand.iter() {
+                assert!(seen.insert(card), "{card} was dealt twice");
+            }
+        }
+        assert_eq!(seen, CardSet::full());
+    }
+
+    #[test]
+    fn game_rng_with_same_seed_deals_same_hands() {
+        let first = deal_hands(&mut GameRng::from_seed(42));
+        let second = deal_hands(&mut GameRng::from_seed(42));
+        assert_eq!(first, second);
+        assert_ne!(first, deal_hands(&mut GameRng::from_seed(43)));
+    }
+
+    #[test]
+    fn deal_with_constraint_meets_hcp_range() {
+        let deal =
+            deal_with_constraint(&mut GameRng::from_seed(48), HandIdentifier::South, 15, 17, 1000)
+                .expect("No matching deal found");
+        assert!((15..=17).contains(&deal.hand(HandIdentifier::South).high_card_points()));
+    }
+
+    #[test]
+    fn deal_with_constraint_gives_up_after_attempts() {
+        let mut rng = GameRng::from_seed(48);
+        assert_eq!(deal_with_constraint(&mut rng, HandIdentifier::South, 38, 40, 10), None);
+        assert_eq!(deal_with_constraint(&mut rng, HandIdentifier::South, 0, 40, 0), None);
+    }
+
+    /// Independently computes the index of a deal, as the inverse of
+    /// [deal_from_permutation]
+    fn deal_index(deal: &Deal) -> u128 {
+        let mut undealt = Card::all().collect::<Vec<_>>();
+        let mut index = 0;
+        let mut radix = 1;
+        for (_, hand) in deal.hands() {
+            let mut rank = 0;
+            let mut remaining = HAND_SIZE;
+            for (i, card) in undealt.iter().enumerate() {
+                if hand.contains(*card) {
+                    remaining -= 1;
+                } else if remaining > 0 {
+                    rank += binomial(undealt.len() - i - 1, remaining - 1);
+                }
+            }
+            index += rank * radix;
+            radix *= binomial(undealt.len(), HAND_SIZE);
+            undealt.retain(|card| !hand.contains(*card));
+        }
+        index
+    }
+
+    #[test]
+    fn deal_count_matches_binomials() {
+        assert_eq!(DEAL_COUNT, binomial(52, 13) * binomial(39, 13) * binomial(26, 13));
+        assert_eq!(binomial(52, 13), 635_013_559_600);
+        assert_eq!(binomial(13, 13), 1);
+    }
+
+    #[test]
+    fn first_and_last_deal_indices() {
+        let suit_per_seat = |deal: &Deal| {
+            deal.hands()
+                .map(|(_, hand)| {
+                    Suit::ALL.map(|suit| hand.suit_lengths()[suit.to_index() as usize])
+                })
+                .collect::<Vec<_>>()
+        };
+        let first = deal_from_permutation(0);
+        assert_eq!(
+            suit_per_seat(&first),
+            [[13, 0, 0, 0], [0, 13, 0, 0], [0, 0, 13, 0], [0, 0, 0, 13]]
+        );
+        let last = deal_from_permutation(DEAL_COUNT - 1);
+        assert_eq!(
+            suit_per_seat(&last),
+            [[0, 0, 0, 13], [0, 0, 13, 0], [0, 13, 0, 0], [13, 0, 0, 0]]
+        );
+        assert_eq!(deal_from_permutation(DEAL_COUNT), first);
+    }
+
+    #[test]
+    fn deal_indices_round_trip() {
+        let mut rng = StdRng::seed_from_u64(55);
+        for _ in 0..200 {
+            let index = rng.gen_range(0..DEAL_COUNT);
+            assert_eq!(deal_index(&deal_from_permutation(index)), index);
+        }
+        assert_eq!(deal_index(&deal_from_permutation(0)), 0);
+        assert_eq!(deal_index(&deal_from_permutation(DEAL_COUNT - 1)), DEAL_COUNT - 1);
+        assert_eq!(deal_index(&deal_from_permutation(u128::MAX)), u128::MAX % DEAL_COUNT);
+    }
+
+    #[test]
+    fn different_indices_change_every_suit() {
+        let mut rng = StdRng::seed_from_u64(55);
+        let deals = (0..20).map(|_| deal_from_permutation(rng.gen())).collect::<Vec<_>>();
+        for seat in HandIdentifier::ALL {
+            for suit in Suit::ALL {
+                let holdings = deals
+                    .iter()
+                    .map(|deal| deal.hand(seat).holding_string(suit, false))
+                    .collect::<BTreeSet<_>>();
+                assert!(holdings.len() > 1, "{seat} always holds the same {suit:?}");
+            }
+        }
+    }
+
+    struct NoOpShuffler;
+
+    impl Shuffler for NoOpShuffler {
+        fn shuffle(&mut self, _: &mut [Card]) {}
+    }
+
+    struct ReverseShuffler;
+
+    impl Shuffler for ReverseShuffler {
+        fn shuffle(&mut self, cards: &mut [Card]) {
+            cards.reverse();
+        }
+    }
+
+    #[test]
+    fn custom_shufflers_are_applied() {
+        let mut deck = Deck::standard();
+        deck.shuffle_with(&mut NoOpShuffler);
+        assert_eq!(deck, Deck::standard());
+
+        deck.shuffle_with(&mut ReverseShuffler);
+        assert_eq!(deck.deal(1), [Card::new(Suit::Clubs, Rank::Two)]);
+    }
+
+    #[test]
+    fn fisher_yates_is_reproducible_from_seed() {
+        let mut first = Deck::standard();
+        let mut second = Deck::standard();
+        first.shuffle_with(&mut FisherYates(StdRng::seed_from_u64(74)));
+        second.shuffle_with(&mut FisherYates(StdRng::seed_from_u64(74)));
+        assert_eq!(first, second);
+        assert_ne!(first, Deck::standard());
+
+        let mut shuffled = Deck::standard();
+        shuffled.shuffle(&mut StdRng::seed_from_u64(74));
+        assert_eq!(first, shuffled);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ordered_vec_lists_seats_from_north() {
+        let deal = deal_from_permutation(0);
+        let expected = deal.hands().map(|(seat, hand)| (seat, hand.clone())).collect::<Vec<_>>();
+        let mut insertion = HandIdentifier::ALL;
+        for _ in 0..4 {
+            insertion.rotate_left(1);
+            let hands = insertion
+                .iter()
+                .rev()
+                .map(|seat| (*seat, deal.hand(*seat).clone()))
+                .collect::<HashMap<_, _>>();
+            assert_eq!(deal_to_ordered_vec(&hands), expected);
+        }
+        assert_eq!(
+            deal_seats_in_order(),
+            [
+                HandIdentifier::North,
+                HandIdentifier::East,
+                HandIdentifier::South,
+                HandIdentifier::West
+            ]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ordered_vec_omits_missing_seats() {
+        let mut hands = deal_from_permutation(0).to_map();
+        hands.remove(&HandIdentifier::East);
+        let seats =
+            deal_to_ordered_vec(&hands).into_iter().map(|(seat, _)| seat).collect::<Vec<_>>();
+        assert_eq!(seats, [HandIdentifier::North, HandIdentifier::South, HandIdentifier::West]);
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Double-dummy analysis: the result of a deal under perfect play by all four
+//! positions, each of which can see every hand.
+
+use core::cmp::Ordering;
+use core::fmt;
+use std::collections::HashMap;
+
+use enum_iterator::{all, reverse_all};
+
+use crate::card_set::CardSet;
+use crate::hand::Hand;
+use crate::{trick, Card, HandIdentifier, Partnership, Rank, Strain, Suit};
+
+/// Returns the number of tricks the side of `declarer` takes from the remaining
+/// cards in `hands` if every position plays perfectly, with `leader` on lead
+/// to the next trick.
+///
+/// Missing positions are treated as holding no cards. Returns an error unless
+/// every hand holds the same number of cards and no card appears in more than
+/// one hand.
+///
+/// This performs an alpha-beta search, using a series of null-window searches
+/// to narrow down the result. Positions at the start of each trick are cached
+/// in a transposition table keyed on the [Hand::to_bits] encoding of each hand,
+/// with ranks renumbered to ignore cards which have already been played.
+pub fn double_dummy_tricks(
+    hands: &HashMap<HandIdentifier, Hand>,
+    trump: Strain,
+    declarer: HandIdentifier,
+    leader: HandIdentifier,
+) -> Result<u8, DoubleDummyError> {
+    let mut solver = Solver {
+        hands: [CardSet::new(); 4],
+        plays: vec![],
+        trump,
+        declaring: declarer.partnership(),
+        table: HashMap::new(),
+    };
+    let mut seen = CardSet::new();
+    for (seat, hand) in hands {
+        for card in hand.iter() {
+            if !seen.insert(card) {
+                return Err(DoubleDummyError::DuplicateCard(card));
+            }
+        }
+        solver.hands[seat.ordinal() as usize] = hand.iter().collect();
+    }
+    let size = solver.hands[0].len();
+    if solver.hands.iter().any(|hand| hand.len() != size) {
+        return Err(DoubleDummyError::UnequalHandSizes);
+    }
+
+    let (mut low, mut high) = (0, size as u8);
+    while low < high {
+        let target = (low + high).div_ceil(2);
+        if solver.search(leader, target - 1, target) >= target {
+            low = target;
+        } else {
+            high = target - 1;
+        }
+    }
+    Ok(low)
+}
+
+/// Error returned when the hands given to [double_dummy_tricks] are not a
+/// valid position
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum DoubleDummyError {
+    /// The hands do not all hold the same number of cards
+    UnequalHandSizes,
+    /// The card is held by more than one hand
+    DuplicateCard(Card),
+}
+
+impl fmt::Display for DoubleDummyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoubleDummyError::UnequalHandSizes => {
+                write!(f, "Every hand must hold the same number of cards")
+            }
+            DoubleDummyError::DuplicateCard(card) => {
+                write!(f, "Card {card} is held by more than one hand")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DoubleDummyError {}
+
+/// Bounds on the number of tricks the declaring side can take from a position
+#[derive(Debug, Copy, Clone)]
+struct Bounds {
+    lower: u8,
+    upper: u8,
+}
+
+struct Solver {
+    /// Cards remaining in each hand, indexed by [HandIdentifier::ordinal]
+    hands: [CardSet; 4],
+    /// Every card played during the search so far. The final `len() % 4`
+    /// plays make up the current trick.
+    plays: Vec<(HandIdentifier, Card)>,
+    trump: Strain,
+    declaring: Partnership,
+    table: HashMap<([u64; 4], HandIdentifier), Bounds>,
+}
+
+impl Solver {
+    /// Returns the number of tricks the declaring side takes from the current
+    /// position, including the trick in progress, where `leader` led the
+    /// current trick.
+    ///
+    /// The result is exact if it lies strictly between `alpha` and `beta`. A
+    /// result at or below `alpha` is an upper bound, and a result at or above
+    /// `beta` is a lower bound. Requires `alpha < beta`.
+    fn search(&mut self, leader: HandIdentifier, mut alpha: u8, mut beta: u8) -> u8 {
+        let played = self.plays.len() % 4;
+        let key = (played == 0).then(|| (self.normalized_hands(), leader));
+        if let Some(key) = key {
+            let remaining = self.hands[leader.ordinal() as usize].len() as u8;
+            if remaining <= alpha {
+                return remaining;
+            }
+
+            if let Some(bounds) = self.table.get(&key) {
+                if bounds.lower >= beta || bounds.lower == bounds.upper {
+                    return bounds.lower;
+                }
+                if bounds.upper <= alpha {
+                    return bounds.upper;
+                }
+                alpha = alpha.max(bounds.lower);
+                beta = beta.min(bounds.upper);
+            }
+        }
+
+        let (window_alpha, window_beta) = (alpha, beta);
+        let start = self.plays.len() - played;
+        let seat = HandIdentifier::iter_from(leader).nth(played).expect("Invalid position");
+        let led = self.plays.get(start).map(|(_, card)| card.suit);
+        let maximizing = seat.partnership() == self.declaring;
+        let mut best = if maximizing { 0 } else { u8::MAX };
+
+        for card in self.candidate_plays(seat, led) {
+            self.hands[seat.ordinal() as usize].remove(card);
+            self.plays.push((seat, card));
+            let value = if played == 3 {
+                let winner = trick::winning_card(
+                    &self.plays[start..],
+                    led.expect("Trick has no led suit"),
+                    self.trump,
+                )
+                .expect("Invalid trick");
+                let won = u8::from(winner.partnership() == self.declaring);
+                if beta <= won {
+                    won
+                } else {
+                    won + self.search(winner, alpha.saturating_sub(won), beta - won)
+                }
+            } else {
+                self.search(leader, alpha, beta)
+            };
+            self.plays.pop();
+            self.hands[seat.ordinal() as usize].insert(card);
+
+            if maximizing {
+                best = best.max(value);
+                alpha = alpha.max(best);
+            } else {
+                best = best.min(value);
+                beta = beta.min(best);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+
+        if let Some(key) = key {
+            let remaining = self.hands[leader.ordinal() as usize].len() as u8;
+            let bounds = self.table.entry(key).or_insert(Bounds { lower: 0, upper: remaining });
+            if best <= window_alpha {
+                bounds.upper = bounds.upper.min(best);
+            } else if best >= window_beta {
+                bounds.lower = bounds.lower.max(best);
+            } else {
+                *bounds = Bounds { lower: best, upper: best };
+            }
+        }
+        best
+    }
+
+    /// Returns the bit encoding of each hand after replacing every card with
+    /// the highest rank it could hold if cards from previous tricks were
+    /// removed from the deck, so that positions which differ only in already
+    /// played cards share a transposition table entry.
+    fn normalized_hands(&self) -> [u64; 4] {
+        let mut result = [CardSet::new(); 4];
+        for suit in all::<Suit>() {
+            let mut ranks = reverse_all::<Rank>();
+            for rank in reverse_all::<Rank>() {
+                let card = Card::new(suit, rank);
+                if let Some(hand) = self.hands.iter().position(|hand| hand.contains(card)) {
+                    let normalized = ranks.next().expect("Too many ranks");
+                    result[hand].insert(Card::new(suit, normalized));
+                }
+            }
+        }
+        result.map(|hand| hand.to_bits())
+    }
+
+    /// Returns the legal plays for `seat`, omitting cards which are equivalent
+    /// to a higher card in the same hand.
+    ///
+    /// Two cards are equivalent when no card between them in rank remains in
+    /// play, because cards played to earlier tricks can no longer affect the
+    /// result. Cards played to the current trick still count as in play.
+    fn candidate_plays(&self, seat: HandIdentifier, led: Option<Suit>) -> Vec<Card> {
+        let hand = self.hands[seat.ordinal() as usize];
+        let following = led.map(|suit| hand.cards_of_suit(suit)).unwrap_or_default();
+        let legal = if following.is_empty() { hand } else { following };
+        let start = self.plays.len() - self.plays.len() % 4;
+        let live = self.plays[start..]
+            .iter()
+            .map(|(_, card)| *card)
+            .collect::<CardSet>()
+            .union(self.hands.iter().fold(CardSet::new(), |result, hand| result.union(*hand)));
+
+        let mut result = vec![];
+        for suit in all::<Suit>() {
+            let mut in_sequence = false;
+            for card in reverse_all::<Rank>().map(|rank| Card::new(suit, rank)) {
+                if !live.contains(card) {
+                    continue;
+                }
+                if legal.contains(card) {
+                    if !in_sequence {
+                        result.push(card);
+                    }
+                    in_sequence = true;
+                } else {
+                    in_sequence = false;
+                }
+            }
+        }
+
+        // Search cards which take the lead in the trick from the opponents
+        // first, cheapest first, followed by the remaining cards from lowest
+        // to highest.
+        if let Some(led) = led {
+            let trick = &self.plays[start..];
+            let winner = trick::winning_card(trick, led, self.trump).expect("Invalid trick");
+            let (_, best) = trick.iter().find(|(s, _)| *s == winner).expect("Winner not found");
+            let partner_winning = winner.partnership() == seat.partnership();
+            result.sort_by_key(|card| {
+                let overtakes =
+                    trick::compare_in_trick(*card, *best, led, self.trump) == Ordering::Greater;
+                (
+                    partner_winning || !overtakes,
+                    trick::rank_strength(card.rank, card.suit, led, self.trump),
+                )
+            });
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_cards;
+    use crate::HandIdentifier::{East, North, South, West};
+
+    fn hands(cards: [(HandIdentifier, &str); 4]) -> HashMap<HandIdentifier, Hand> {
+        cards
+            .into_iter()
+            .map(|(seat, cards)| (seat, parse_cards(cards).unwrap().into_iter().collect()))
+            .collect()
+    }
+
+    /// North and West each hold two winners in each major, while South holds
+    /// the only clubs and East the only diamonds.
+    fn blocked_ending() -> HashMap<HandIdentifier, Hand> {
+        hands([
+            (North, "AS KS 3H 2H"),
+            (East, "5D 4D 3D 2D"),
+            (South, "5C 4C 3C 2C"),
+            (West, "AH KH 3S 2S"),
+        ])
+    }
+
+    #[test]
+    fn no_trump_ending_depends_on_leader() {
+        let hands = blocked_ending();
+        let tricks = |leader| double_dummy_tricks(&hands, Strain::NoTrump, North, leader);
+        assert_eq!(tricks(South), Ok(4));
+        assert_eq!(tricks(North), Ok(2));
+        assert_eq!(tricks(West), Ok(2));
+        assert_eq!(tricks(East), Ok(0));
+    }
+
+    #[test]
+    fn declarer_selects_the_counted_side() {
+        let hands = blocked_ending();
+        assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, East, East), Ok(4));
+        assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, West, South), Ok(0));
+        assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, South, North), Ok(2));
+    }
+
+    #[test]
+    fn trumps_let_the_long_hand_ruff() {
+        let hands = blocked_ending();
+        assert_eq!(double_dummy_tricks(&hands, Strain::Clubs, North, North), Ok(4));
+        assert_eq!(double_dummy_tricks(&hands, Strain::Clubs, North, East), Ok(4));
+        assert_eq!(double_dummy_tricks(&hands, Strain::Diamonds, North, North), Ok(0));
+        assert_eq!(double_dummy_tricks(&hands, Strain::Diamonds, North, South), Ok(0));
+    }
+
+    #[test]
+    fn finesse_against_king_makes_two_tricks_from_any_lead() {
+        let hands = hands([
+            (North, "AS QS 2H 2D"),
+            (East, "JS 4S 4H 4D"),
+            (South, "3S 2S 3H 3D"),
+            (West, "KS 5S 5H 5D"),
+        ]);
+        for leader in all::<HandIdentifier>() {
+            assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, South, leader), Ok(2));
+        }
+        assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, West, West), Ok(2));
+    }
+
+    #[test]
+    fn single_trick_ending() {
+        let hands = hands([(North, "AS"), (East, "KS"), (South, "2S"), (West, "2H")]);
+        assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, North, West), Ok(0));
+        assert_eq!(double_dummy_tricks(&hands, Strain::NoTrump, North, South), Ok(1));
+        assert_eq!(double_dummy_tricks(&hands, Strain::Hearts, North, South), Ok(0));
+    }
+
+    #[test]
+    fn rejects_invalid_positions() {
+        let duplicate = hands([(North, "AS"), (East, "KS"), (South, "AS"), (West, "2H")]);
+        assert_eq!(
+            double_dummy_tricks(&duplicate, Strain::NoTrump, North, North),
+            Err(DoubleDummyError::DuplicateCard(Card::new(Suit::Spades, Rank::Ace)))
+        );
+
+        let mut unequal = blocked_ending();
+        unequal.get_mut(&West).unwrap().remove(Card::new(Suit::Spades, Rank::Two));
+        assert_eq!(
+            double_dummy_tricks(&unequal, Strain::NoTrump, North, North),
+            Err(DoubleDummyError::UnequalHandSizes)
+        );
+
+        let mut missing = blocked_ending();
+        missing.remove(&East);
+        assert_eq!(
+            double_dummy_tricks(&missing, Strain::NoTrump, North, North),
+            Err(DoubleDummyError::UnequalHandSizes)
+        );
+
+        assert_eq!(double_dummy_tricks(&HashMap::new(), Strain::NoTrump, North, North), Ok(0));
+    }
+}
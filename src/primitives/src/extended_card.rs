@@ -0,0 +1,66 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+
+use crate::{Card, Color};
+
+/// A playing card for variants which add jokers to the standard 52-card deck.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtendedCard {
+    Standard(Card),
+    Joker(Color),
+}
+
+impl fmt::Display for ExtendedCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtendedCard::Standard(card) => card.fmt(f),
+            ExtendedCard::Joker(_) => write!(f, "🃏"),
+        }
+    }
+}
+
+impl ExtendedCard {
+    /// Iterates over all 54 cards: the 52 standard cards in ascending order
+    /// followed by the red joker and then the black joker.
+    pub fn all_with_jokers() -> impl Iterator<Item = ExtendedCard> {
+        Card::all()
+            .map(ExtendedCard::Standard)
+            .chain([ExtendedCard::Joker(Color::Red), ExtendedCard::Joker(Color::Black)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::{Rank, Suit};
+
+    #[test]
+    fn all_with_jokers_yields_54_cards() {
+        let cards = ExtendedCard::all_with_jokers().collect::<Vec<_>>();
+        assert_eq!(cards.len(), 54);
+        assert_eq!(cards.iter().filter(|card| matches!(card, ExtendedCard::Joker(_))).count(), 2);
+    }
+
+    #[test]
+    fn joker_display() {
+        assert_eq!(ExtendedCard::Joker(Color::Red).to_string(), "🃏");
+        assert_eq!(ExtendedCard::Standard(Card::new(Suit::Hearts, Rank::Ace)).to_string(), "A♥");
+    }
+}
@@ -0,0 +1,372 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::bid::Bid;
+use crate::hand::HAND_SIZE;
+use crate::scoring::{self, Contract};
+use crate::trick::{self, Trick, TrickError, TrickTally};
+use crate::HandIdentifier;
+
+/// Top-level state machine for a single round of play.
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum GameState {
+    Auction(AuctionState),
+    Play(PlayState),
+    Complete(Score),
+}
+
+/// State of the bidding phase of a round
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct AuctionState {
+    /// Position which made the first bid of the auction
+    pub dealer: HandIdentifier,
+    /// All bids made so far, in order
+    pub bids: Vec<Bid>,
+}
+
+impl AuctionState {
+    /// Returns the position whose turn it is to bid
+    pub fn next_bidder(&self) -> HandIdentifier {
+        self.bidder(self.bids.len())
+    }
+
+    /// Returns the position which made the bid at `index` in [Self::bids]
+    fn bidder(&self, index: usize) -> HandIdentifier {
+        HandIdentifier::iter_from(self.dealer).nth(index % 4).expect("Invalid position")
+    }
+
+    /// Returns true once three consecutive passes have followed a bid, or all
+    /// four positions have passed.
+    fn is_complete(&self) -> bool {
+        self.bids.len() >= 4 && self.bids.iter().rev().take(3).all(|bid| *bid == Bid::Pass)
+    }
+
+    /// Returns the contract established by this auction, or None if every
+    /// position passed.
+    ///
+    /// The declarer is the first position of the partnership which made the
+    /// final contract bid to have bid that contract's strain. Doubles and
+    /// redoubles are not reflected in the contract.
+    fn contract(&self) -> Option<Contract> {
+        let (last_index, level, strain) =
+            self.bids.iter().enumerate().rev().find_map(|(index, bid)| match bid {
+                Bid::Contract { level, strain } => Some((index, *level, *strain)),
+                _ => None,
+            })?;
+        let partnership = self.bidder(last_index).partnership();
+        let declarer = self
+            .bids
+            .iter()
+            .enumerate()
+            .find(|(index, bid)| {
+                matches!(bid, Bid::Contract { strain: s, .. } if *s == strain)
+                    && self.bidder(*index).partnership() == partnership
+            })
+            .map(|(index, _)| self.bidder(index))?;
+        Some(Contract { level, trump: strain, declarer })
+    }
+}
+
+/// State of the trick-taking phase of a round
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub struct PlayState {
+    pub contract: Contract,
+    pub tricks: TrickTally,
+    pub tricks_played: u8,
+    /// Position which leads the next trick
+    pub leader: HandIdentifier,
+}
+
+/// Final result of a round
+#[derive(PartialEq, Eq, Hash, Debug, Clone)]
+pub enum Score {
+    /// All four positions passed, so no contract was played
+    PassedOut,
+    /// The contract was played out, with the declaring side winning
+    /// `tricks_won` tricks for a score of `points`
+    Played { contract: Contract, tricks_won: u8, points: i32 },
+}
+
+/// Error returned when an action cannot be applied to a [GameState]
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum GameError {
+    /// The action is not valid in the current phase of the round
+    WrongPhase,
+    /// The bid is not legal at this point in the auction
+    IllegalBid(Bid),
+    /// Fewer than four cards have been played to the trick
+    IncompleteTrick,
+    /// The trick's plays are not valid, for example because it was not led by
+    /// the position on lead
+    InvalidTrick(TrickError),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::WrongPhase => write!(f, "Action is not valid in the current phase"),
+            GameError::IllegalBid(bid) => write!(f, "Illegal bid: {bid:?}"),
+            GameError::IncompleteTrick => write!(f, "The trick has not been completed"),
+            GameError::InvalidTrick(error) => write!(f, "Invalid trick: {error}"),
+        }
+    }
+}
+
+impl core::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            GameError::InvalidTrick(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl GameState {
+    /// Begins a round in the auction phase, with `dealer` bidding first.
+    pub fn new(dealer: HandIdentifier) -> Self {
+        GameState::Auction(AuctionState { dealer, bids: vec![] })
+    }
+
+    /// Records a bid by the next bidder in the auction.
+    ///
+    /// When this ends the auction, the round moves to the play phase, or is
+    /// complete if every position passed.
+    pub fn bid(&mut self, bid: Bid) -> Result<(), GameError> {
+        let GameState::Auction(auction) = self else {
+            return Err(GameError::WrongPhase);
+        };
+        if !bid.is_legal_after(&auction.bids) {
+            return Err(GameError::IllegalBid(bid));
+        }
+
+        auction.bids.push(bid);
+        if auction.is_complete() {
+            *self = match auction.contract() {
+                Some(contract) => GameState::Play(PlayState {
+                    contract,
+                    tricks: TrickTally::default(),
+                    tricks_played: 0,
+                    leader: trick::opening_leader(contract.declarer),
+                }),
+                None => GameState::Complete(Score::PassedOut),
+            };
+        }
+        Ok(())
+    }
+
+    /// Records a completed trick in the play phase and returns its winner,
+    /// who leads the next trick.
+    ///
+    /// Returns an error if the trick was not led by the position on lead. The
+    /// round is complete and scored once all tricks have been played.
+    pub fn record_trick(&mut self, trick: &Trick) -> Result<HandIdentifier, GameError> {
+        let GameState::Play(play) = self else {
+            return Err(GameError::WrongPhase);
+        };
+        if !trick.is_complete() {
+            return Err(GameError::IncompleteTrick);
+        }
+        if trick.plays()[0].0 != play.leader {
+            return Err(GameError::InvalidTrick(TrickError::WrongLeader));
+        }
+
+        let winner = trick.winner(play.contract.trump).map_err(GameError::InvalidTrick)?;
+        play.tricks.record(winner);
+        play.tricks_played += 1;
+        play.leader = winner;
+        if usize::from(play.tricks_played) == HAND_SIZE {
+            let contract = play.contract;
+            let tricks_won = play.tricks.tricks(contract.declarer.partnership());
+            let points = scoring::score_contract(&contract, tricks_won);
+            *self = GameState::Complete(Score::Played { contract, tricks_won, points });
+        }
+        Ok(winner)
+    }
+}
+
+/// Returns the position which deals the round after `current` deals.
+pub fn next_dealer(current: HandIdentifier) -> HandIdentifier {
+    current.next()
+}
+
+/// Iterator over the dealers for each round of a match
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct DealerRotation {
+    dealer: HandIdentifier,
+    remaining: usize,
+}
+
+impl DealerRotation {
+    /// Creates an iterator over `rounds` dealers, starting with `first`.
+    pub fn new(first: HandIdentifier, rounds: usize) -> Self {
+        Self { dealer: first, remaining: rounds }
+    }
+}
+
+impl Iterator for DealerRotation {
+    type Item = HandIdentifier;
+
+    fn next(&mut self) -> Option<HandIdentifier> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let dealer = self.dealer;
+        self.dealer = next_dealer(dealer);
+        self.remaining -= 1;
+        Some(dealer)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for DealerRotation {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Card, Strain};
+
+    /// Builds a complete trick led by `leader` in which `winner` takes the
+    /// trick, by ruffing with a Heart if they did not lead
+    fn trick_won_by(leader: HandIdentifier, winner: HandIdentifier) -> Trick {
+        let mut trick = Trick::new();
+        let mut low_spades = ["2S", "3S", "4S"].into_iter();
+        for seat in HandIdentifier::iter_from(leader).take(4) {
+            let card = if seat == leader {
+                "AS"
+            } else if seat == winner {
+                "2H"
+            } else {
+                low_spades.next().unwrap()
+            };
+            trick.play(seat, card.parse::<Card>().unwrap()).unwrap();
+        }
+        trick
+    }
+
+    fn two_hearts_by_north() -> GameState {
+        let mut game = GameState::new(HandIdentifier::North);
+        for bid in [Bid::contract(1, Strain::Hearts), Bid::Pass, Bid::contract(2, Strain::Hearts)] {
+            game.bid(bid).unwrap();
+        }
+        for _ in 0..3 {
+            game.bid(Bid::Pass).unwrap();
+        }
+        game
+    }
+
+    #[test]
+    fn all_pass_auction_is_passed_out() {
+        let mut game = GameState::new(HandIdentifier::East);
+        for _ in 0..3 {
+            game.bid(Bid::Pass).unwrap();
+            assert!(matches!(game, GameState::Auction(_)));
+        }
+        game.bid(Bid::Pass).unwrap();
+        assert_eq!(game, GameState::Complete(Score::PassedOut));
+        assert_eq!(game.bid(Bid::Pass), Err(GameError::WrongPhase));
+        let trick = trick_won_by(HandIdentifier::North, HandIdentifier::North);
+        assert_eq!(game.record_trick(&trick), Err(GameError::WrongPhase));
+    }
+
+    #[test]
+    fn auction_tracks_next_bidder_and_rejects_illegal_bids() {
+        let mut game = GameState::new(HandIdentifier::West);
+        game.bid(Bid::contract(1, Strain::Spades)).unwrap();
+        assert_eq!(
+            game.bid(Bid::contract(1, Strain::Hearts)),
+            Err(GameError::IllegalBid(Bid::contract(1, Strain::Hearts)))
+        );
+        let GameState::Auction(auction) = &game else { panic!("Expected auction") };
+        assert_eq!(auction.next_bidder(), HandIdentifier::North);
+        let trick = trick_won_by(HandIdentifier::North, HandIdentifier::North);
+        assert_eq!(game.record_trick(&trick), Err(GameError::WrongPhase));
+    }
+
+    #[test]
+    fn completed_auction_moves_to_play_and_then_scores() {
+        let mut game = two_hearts_by_north();
+        let expected =
+            Contract { level: 2, trump: Strain::Hearts, declarer: HandIdentifier::North };
+        let GameState::Play(play) = &game else { panic!("Expected play, got {game:?}") };
+        assert_eq!(play.contract, expected);
+        assert_eq!(play.leader, HandIdentifier::East);
+
+        let mut leader = play.leader;
+        for trick in 0..13 {
+            assert!(matches!(game, GameState::Play(_)));
+            let winner = if trick < 8 { HandIdentifier::South } else { HandIdentifier::East };
+            assert_eq!(game.record_trick(&trick_won_by(leader, winner)), Ok(winner));
+            leader = winner;
+        }
+        assert_eq!(
+            game,
+            GameState::Complete(Score::Played { contract: expected, tricks_won: 8, points: 110 })
+        );
+    }
+
+    #[test]
+    fn dealer_rotates_clockwise() {
+        let dealers = DealerRotation::new(HandIdentifier::North, 4).collect::<Vec<_>>();
+        assert_eq!(
+            dealers,
+            [
+                HandIdentifier::North,
+                HandIdentifier::East,
+                HandIdentifier::South,
+                HandIdentifier::West
+            ]
+        );
+        assert_eq!(next_dealer(HandIdentifier::West), HandIdentifier::North);
+    }
+
+    #[test]
+    fn dealer_rotation_length() {
+        let rotation = DealerRotation::new(HandIdentifier::South, 6);
+        assert_eq!(rotation.len(), 6);
+        assert_eq!(rotation.last(), Some(HandIdentifier::West));
+        assert_eq!(DealerRotation::new(HandIdentifier::South, 0).next(), None);
+    }
+
+    #[test]
+    fn record_trick_rejects_incomplete_and_out_of_turn_tricks() {
+        let mut game = two_hearts_by_north();
+        let before = game.clone();
+
+        let mut partial = Trick::new();
+        partial.play(HandIdentifier::East, "AS".parse().unwrap()).unwrap();
+        assert_eq!(game.record_trick(&partial), Err(GameError::IncompleteTrick));
+
+        let led_out_of_turn = trick_won_by(HandIdentifier::South, HandIdentifier::South);
+        assert_eq!(
+            game.record_trick(&led_out_of_turn),
+            Err(GameError::InvalidTrick(TrickError::WrongLeader))
+        );
+        assert_eq!(game, before);
+
+        game.record_trick(&trick_won_by(HandIdentifier::East, HandIdentifier::North)).unwrap();
+        let GameState::Play(play) = &game else { panic!("Expected play, got {game:?}") };
+        assert_eq!(play.leader, HandIdentifier::North);
+        assert_eq!(
+            game.record_trick(&trick_won_by(HandIdentifier::East, HandIdentifier::East)),
+            Err(GameError::InvalidTrick(TrickError::WrongLeader))
+        );
+    }
+}
@@ -0,0 +1,700 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt;
+
+use enum_iterator::{all, reverse_all};
+
+use crate::card_set::CardSet;
+use crate::{Card, Color, Rank, RankCategory, Suit};
+
+/// Number of cards dealt to each hand at the start of a round
+pub const HAND_SIZE: usize = 13;
+
+/// The cards held by one [crate::HandIdentifier] during a round, at most
+/// [HAND_SIZE].
+///
+/// Cards are kept in ascending [Card] order. The size limit is enforced when
+/// cards are added, see [Hand::try_insert].
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub struct Hand {
+    cards: Vec<Card>,
+}
+
+impl Hand {
+    /// Groups the ranks in this hand by suit, omitting suits with no cards.
+    ///
+    /// Ranks within each suit are sorted from highest to lowest, the order in
+    /// which players typically view their cards.
+    pub fn by_suit(&self) -> BTreeMap<Suit, Vec<Rank>> {
+        let mut result = BTreeMap::<Suit, Vec<Rank>>::new();
+        for card in self.cards.iter().rev() {
+            result.entry(card.suit).or_default().push(card.rank);
+        }
+        result
+    }
+
+    /// Returns the same groups as [Self::by_suit] as a list, ordered from
+    /// Clubs up to Spades, or from Spades down to Clubs if `descending_suits`
+    /// is true as is conventional in bridge.
+    pub fn by_suit_ordered(&self, descending_suits: bool) -> Vec<(Suit, Vec<Rank>)> {
+        let mut result = self.by_suit().into_iter().collect::<Vec<_>>();
+        if descending_suits {
+            result.reverse();
+        }
+        result
+    }
+
+    /// Iterates over the cards in this hand in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = Card> + '_ {
+        self.cards.iter().copied()
+    }
+
+    /// Returns the cards in this hand in ascending order
+    pub fn sorted(&self) -> Vec<Card> {
+        self.cards.clone()
+    }
+
+    /// Returns the cards in this hand in ascending order, without copying
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Returns the number of cards in this hand
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.cards.binary_search(&card).is_ok()
+    }
+
+    /// Adds a card to this hand, failing if the hand already holds it or
+    /// already holds [HAND_SIZE] cards.
+    pub fn try_insert(&mut self, card: Card) -> Result<(), HandError> {
+        match self.cards.binary_search(&card) {
+            Ok(_) => Err(HandError::DuplicateCard(card)),
+            Err(_) if self.cards.len() >= HAND_SIZE => Err(HandError::TooManyCards),
+            Err(index) => {
+                self.cards.insert(index, card);
+                Ok(())
+            }
+        }
+    }
+
+    /// Builds a hand from a sequence of cards, failing on the first card which
+    /// appears more than once or if there are more than [HAND_SIZE] cards.
+    pub fn try_from_iter(iter: impl IntoIterator<Item = Card>) -> Result<Self, HandError> {
+        let mut result = Self::default();
+        for card in iter {
+            result.try_insert(card)?;
+        }
+        Ok(result)
+    }
+
+    /// Encodes this hand as a bitmask with bit [Card::to_index] set for each
+    /// card held, suitable as a compact key for caching.
+    pub fn to_bits(&self) -> u64 {
+        self.card_set().to_bits()
+    }
+
+    /// Decodes a hand from the bitmask produced by [Self::to_bits]. Bits above
+    /// the 52 card positions are ignored.
+    ///
+    /// Returns [HandError::TooManyCards] if more than [HAND_SIZE] bits are set.
+    pub fn from_bits(bits: u64) -> Result<Self, HandError> {
+        let set = CardSet::from_bits(bits);
+        if set.len() > HAND_SIZE {
+            return Err(HandError::TooManyCards);
+        }
+        Ok(Self::from_card_set(set))
+    }
+
+    /// Returns the cards held by both this hand and `other`
+    pub fn intersection(&self, other: &Hand) -> Hand {
+        Self::from_card_set(self.card_set().intersection(other.card_set()))
+    }
+
+    /// Returns the cards held by this hand which are not held by `other`
+    pub fn difference(&self, other: &Hand) -> Hand {
+        Self::from_card_set(self.card_set().difference(other.card_set()))
+    }
+
+    /// Removes a card from this hand. Returns true if the card was present.
+    pub fn remove(&mut self, card: Card) -> bool {
+        match self.cards.binary_search(&card) {
+            Ok(index) => {
+                self.cards.remove(index);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+
+    /// Removes each of `cards` from this hand. Returns the number of cards
+    /// which were present and removed.
+    pub fn remove_all(&mut self, cards: &[Card]) -> usize {
+        cards.iter().filter(|card| self.remove(**card)).count()
+    }
+
+    /// Returns true if this hand holds no cards of the given suit
+    pub fn is_void_in(&self, suit: Suit) -> bool {
+        !self.cards.iter().any(|card| card.suit == suit)
+    }
+
+    /// Partitions the cards in this hand into (black cards, red cards), each in
+    /// ascending order, see [Suit::color].
+    pub fn split_by_color(&self) -> (Vec<Card>, Vec<Card>) {
+        self.cards.iter().partition(|card| card.suit.color() == Color::Black)
+    }
+
+    /// Returns the ranks this hand holds in `suit` from high to low as a string
+    /// such as "AKQ", using [Rank::to_ascii]. If `spot_as_x` is true, spot
+    /// cards are written as 'x', e.g. "AKxxx".
+    pub fn holding_string(&self, suit: Suit, spot_as_x: bool) -> String {
+        self.cards
+            .iter()
+            .rev()
+            .filter(|card| card.suit == suit)
+            .map(|card| match card.rank.category() {
+                RankCategory::Spot if spot_as_x => 'x',
+                _ => card.rank.to_ascii(),
+            })
+            .collect()
+    }
+
+    /// Renders this hand as a bridge diagram, with one line per suit from
+    /// Spades down to Clubs. Each line shows the suit symbol followed by the
+    /// ranks held in that suit from high to low, or by an em-dash for a void.
+    pub fn display_by_suit(&self) -> String {
+        let by_suit = self.by_suit();
+        reverse_all::<Suit>()
+            .map(|suit| match by_suit.get(&suit) {
+                Some(ranks) => {
+                    let ranks = ranks.iter().map(Rank::to_string).collect::<Vec<_>>();
+                    format!("{suit} {}", ranks.join(" "))
+                }
+                None => format!("{suit} —"),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Evaluates this hand using the standard 4-3-2-1 high card point count,
+    /// see [Rank::points_hcp].
+    pub fn high_card_points(&self) -> u8 {
+        self.cards.iter().map(|card| card.rank.points_hcp()).sum()
+    }
+
+    /// Returns the number of honor cards in this hand, see [Rank::is_honor]
+    pub fn honor_count(&self) -> u8 {
+        self.cards.iter().filter(|card| card.rank.is_honor()).count() as u8
+    }
+
+    /// Returns the number of spot cards in this hand, i.e. cards which are not
+    /// honors
+    pub fn spot_card_count(&self) -> u8 {
+        self.cards.iter().filter(|card| card.rank.category() == RankCategory::Spot).count() as u8
+    }
+
+    /// Evaluates the shape of this hand by awarding points for short suits: 3
+    /// points for each void, 2 for each singleton, and 1 for each doubleton.
+    pub fn distribution_points(&self) -> u8 {
+        self.suit_lengths()
+            .iter()
+            .map(|length| match length {
+                0 => 3,
+                1 => 2,
+                2 => 1,
+                _ => 0,
+            })
+            .sum()
+    }
+
+    /// Returns the suit in which this hand holds the most cards. Ties are
+    /// broken in favor of the higher-ranking suit.
+    pub fn longest_suit(&self) -> Suit {
+        let lengths = self.suit_lengths();
+        all::<Suit>().max_by_key(|suit| lengths[suit.to_index() as usize]).expect("No suits")
+    }
+
+    /// Returns the suit in which this hand holds the fewest cards. Ties are
+    /// broken in favor of the higher-ranking suit.
+    pub fn shortest_suit(&self) -> Suit {
+        let lengths = self.suit_lengths();
+        all::<Suit>()
+            .max_by_key(|suit| Reverse(lengths[suit.to_index() as usize]))
+            .expect("No suits")
+    }
+
+    /// Returns true if this hand has a balanced distribution, i.e. no voids,
+    /// no singletons, and at most one doubleton. For a full hand these are the
+    /// 4-3-3-3, 4-4-3-2, and 5-3-3-2 shapes.
+    pub fn is_balanced(&self) -> bool {
+        let lengths = self.suit_lengths();
+        lengths.iter().all(|&length| length >= 2)
+            && lengths.iter().filter(|&&length| length == 2).count() <= 1
+    }
+
+    /// Counts the top tricks this hand holds in `suit`: the number of
+    /// consecutive ranks held starting from the Ace and working downwards.
+    ///
+    /// For example, A-K-Q yields 3 top tricks, while A-Q yields 1 and K-Q
+    /// yields none.
+    ///
+    /// This does not depend on the trump strain. Whether a top card in a side
+    /// suit can be ruffed depends on the opponents' holdings, which a single
+    /// hand cannot see.
+    pub fn top_tricks_in(&self, suit: Suit) -> u8 {
+        let mut next = Some(Rank::Ace);
+        let mut result = 0;
+        for card in self.cards.iter().rev().filter(|card| card.suit == suit) {
+            if Some(card.rank) != next {
+                break;
+            }
+            result += 1;
+            next = card.rank.predecessor();
+        }
+        result
+    }
+
+    /// Estimates the defensive strength of this hand in quick tricks, summed
+    /// over each suit.
+    ///
+    /// A suit holding A-K counts as 2 quick tricks, A-Q as 1.5, a lone Ace or
+    /// K-Q as 1, and a King guarded by at least one other card as 0.5. A
+    /// singleton King counts as nothing.
+    pub fn quick_tricks(&self) -> f32 {
+        self.by_suit()
+            .values()
+            .map(|ranks| {
+                let has = |rank: Rank| ranks.contains(&rank);
+                match (has(Rank::Ace), has(Rank::King), has(Rank::Queen)) {
+                    (true, true, _) => 2.0,
+                    (true, false, true) => 1.5,
+                    (true, false, false) => 1.0,
+                    (false, true, true) => 1.0,
+                    (false, true, false) if ranks.len() >= 2 => 0.5,
+                    _ => 0.0,
+                }
+            })
+            .sum()
+    }
+
+    /// Returns the (lowest rank, highest rank, number of cards) this hand holds
+    /// in `suit`, or None if the hand is void in that suit.
+    pub fn suit_stats(&self, suit: Suit) -> Option<(Rank, Rank, u8)> {
+        let mut ranks = self.cards.iter().filter(|card| card.suit == suit).map(|card| card.rank);
+        let lowest = ranks.next()?;
+        let (highest, count) = ranks.fold((lowest, 1), |(_, count), rank| (rank, count + 1));
+        Some((lowest, highest, count))
+    }
+
+    /// Returns the number of cards this hand holds in each suit, indexed by
+    /// [Suit::to_index].
+    pub fn suit_lengths(&self) -> [u8; 4] {
+        let mut result = [0; 4];
+        for card in &self.cards {
+            result[card.suit.to_index() as usize] += 1;
+        }
+        result
+    }
+
+    fn card_set(&self) -> CardSet {
+        self.iter().collect()
+    }
+
+    fn from_card_set(set: CardSet) -> Self {
+        Self { cards: set.iter().collect() }
+    }
+}
+
+/// Generates a hand of between 1 and [HAND_SIZE] distinct cards.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Hand {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut remaining = Card::all().collect::<Vec<_>>();
+        let mut cards = vec![];
+        for _ in 0..u.int_in_range(1..=HAND_SIZE)? {
+            cards.push(remaining.swap_remove(u.choose_index(remaining.len())?));
+        }
+        Ok(cards.into_iter().collect())
+    }
+}
+
+/// Collects cards into a hand.
+///
+/// Panics if any card appears more than once or if there are more than
+/// [HAND_SIZE] cards, see [Hand::try_from_iter] for a fallible version.
+impl FromIterator<Card> for Hand {
+    fn from_iter<T: IntoIterator<Item = Card>>(iter: T) -> Self {
+        match Self::try_from_iter(iter) {
+            Ok(hand) => hand,
+            Err(error) => panic!("{error}"),
+        }
+    }
+}
+
+/// Error returned when a card cannot be added to a [Hand]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum HandError {
+    /// The hand already holds this card
+    DuplicateCard(Card),
+    /// The hand already holds [HAND_SIZE] cards
+    TooManyCards,
+}
+
+impl fmt::Display for HandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HandError::DuplicateCard(card) => write!(f, "Duplicate card: {card}"),
+            HandError::TooManyCards => write!(f, "A hand holds at most {HAND_SIZE} cards"),
+        }
+    }
+}
+
+impl core::error::Error for HandError {}
+
+/// Builds a [Hand] from string literals in the format accepted by [Card]'s
+/// `FromStr` implementation, e.g. `hand!["AS", "KH", "3D"]`.
+///
+/// Panics if any token is not a valid card, if a card appears twice, or if
+/// there are more than [crate::hand::HAND_SIZE] cards.
+#[macro_export]
+macro_rules! hand {
+    ($($card:expr),* $(,)?) => {
+        [$($card),*]
+            .into_iter()
+            .map(|token: &str| {
+                token
+                    .parse::<$crate::Card>()
+                    .unwrap_or_else(|error| panic!("Invalid card '{token}' in hand!: {error}"))
+            })
+            .collect::<$crate::hand::Hand>()
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::parse_cards;
+
+    fn parse_hand(cards: &str) -> Hand {
+        parse_cards(cards).unwrap().into_iter().collect()
+    }
+
+    fn mixed_hand() -> Hand {
+        parse_hand("AS 7S 2S KH QH 9H 4H 10D 3D QC JC 6C 5C")
+    }
+
+    #[test]
+    fn by_suit_groups_ranks_descending() {
+        let by_suit = mixed_hand().by_suit();
+        assert_eq!(by_suit[&Suit::Spades], vec![Rank::Ace, Rank::Seven, Rank::Two]);
+        assert_eq!(by_suit[&Suit::Hearts], vec![Rank::King, Rank::Queen, Rank::Nine, Rank::Four]);
+        assert_eq!(by_suit[&Suit::Diamonds], vec![Rank::Ten, Rank::Three]);
+        assert_eq!(by_suit[&Suit::Clubs], vec![Rank::Queen, Rank::Jack, Rank::Six, Rank::Five]);
+    }
+
+    #[test]
+    fn sorted_is_ascending() {
+        let sorted = mixed_hand().sorted();
+        assert_eq!(sorted.len(), 13);
+        assert!(sorted.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn contains_and_remove() {
+        let mut hand = mixed_hand();
+        let king_of_hearts = Card::new(Suit::Hearts, Rank::King);
+        assert!(hand.contains(king_of_hearts));
+        assert!(hand.remove(king_of_hearts));
+        assert!(!hand.contains(king_of_hearts));
+        assert!(!hand.remove(king_of_hearts));
+        assert_eq!(hand.len(), 12);
+    }
+
+    #[test]
+    fn hand_holds_at_most_hand_size_cards() {
+        let mut hand = mixed_hand();
+        assert_eq!(
+            hand.try_insert(Card::new(Suit::Spades, Rank::King)),
+            Err(HandError::TooManyCards)
+        );
+        assert_eq!(Hand::try_from_iter(Card::all()), Err(HandError::TooManyCards));
+    }
+
+    #[test]
+    #[should_panic]
+    fn collecting_too_many_cards_panics() {
+        let _ = Card::all().collect::<Hand>();
+    }
+
+    #[test]
+    fn four_aces_score_sixteen_hcp() {
+        let hand = parse_hand("AS AH AD AC 2S 3S 4H 5H 6D 7D 8C 9C 10C");
+        assert_eq!(hand.high_card_points(), 16);
+        assert_eq!(mixed_hand().high_card_points(), 4 + 3 + 2 + 2 + 1);
+    }
+
+    #[test]
+    fn distribution_points_for_short_suits() {
+        let void_in_hearts = parse_hand("AS KS QS JS 10S 9S AD KD QD 2D 3C 4C 5C");
+        assert_eq!(void_in_hearts.distribution_points(), 3);
+
+        let singleton_and_doubleton = parse_hand("AS KS QS JS 10S 9S AD KD QD 2D 3C 4C 5H");
+        assert_eq!(singleton_and_doubleton.distribution_points(), 2 + 1);
+        assert_eq!(mixed_hand().distribution_points(), 1);
+    }
+
+    #[test]
+    fn suit_lengths_in_suit_order() {
+        let hand = parse_hand("AS KS 2S JH 10H 9H 8H QD 4D 3D 7C 6C 5C");
+        assert_eq!(hand.suit_lengths(), [3, 3, 4, 3]);
+        let hand = parse_hand("AS KS QH JH 10H 9H 8D 7D 6D 5D 4C 3C 2C");
+        assert_eq!(hand.suit_lengths(), [3, 4, 4, 2]);
+        assert_eq!(hand.suit_lengths().iter().sum::<u8>() as usize, hand.len());
+    }
+
+    #[test]
+    fn display_by_suit_renders_diagram() {
+        let hand = parse_hand("AS KS 7S QH 3H JC 9C 8C 2C");
+        assert_eq!(hand.display_by_suit(), "♠ A K 7\n♥ Q 3\n♦ —\n♣ J 9 8 2");
+    }
+
+    #[test]
+    fn top_tricks_count_unbroken_run_from_ace() {
+        let hand = parse_hand("AS KS QS 2S AH QH 5H KD QD JD");
+        assert_eq!(hand.top_tricks_in(Suit::Spades), 3);
+        assert_eq!(hand.top_tricks_in(Suit::Hearts), 1);
+        assert_eq!(hand.top_tricks_in(Suit::Diamonds), 0);
+        assert_eq!(hand.top_tricks_in(Suit::Clubs), 0);
+    }
+
+    #[test]
+    fn balanced_and_unbalanced_hands() {
+        assert!(parse_hand("AS KS QS JS 10H 9H 8H 7D 6D 5D 4C 3C 2C").is_balanced());
+        assert!(parse_hand("AS KS QS JS 10H 9H 8H 7H 6D 5D 4D 3C 2C").is_balanced());
+        assert!(parse_hand("AS KS QS JS 10S 9H 8H 7H 6D 5D 4D 3C 2C").is_balanced());
+        assert!(!parse_hand("AS KS QS JS 10S 9H 8H 7H 6H 5D 4D 3D 2C").is_balanced());
+        assert!(!parse_hand("AS KS QS JS 10S 9H 8H 7H 6H 5D 4D 3C 2C").is_balanced());
+    }
+
+    #[test]
+    fn longest_and_shortest_suit_break_ties_toward_higher_suit() {
+        let hand = parse_hand("AS KS QS JS 10H 9H 8H 7H 6D 5D 4D 3C 2C");
+        assert_eq!(hand.longest_suit(), Suit::Spades);
+        assert_eq!(hand.shortest_suit(), Suit::Clubs);
+
+        let hand = parse_hand("AS KS 10H 9H 8H 7H 6D 5D 4D 3D 2C 3C 4C");
+        assert_eq!(hand.longest_suit(), Suit::Hearts);
+        assert_eq!(hand.shortest_suit(), Suit::Spades);
+    }
+
+    #[test]
+    fn honor_count() {
+        assert_eq!(parse_hand("AS 10H JD 9C 2C").honor_count(), 3);
+        assert_eq!(mixed_hand().honor_count(), 6);
+    }
+
+    #[test]
+    fn quick_tricks() {
+        assert_eq!(parse_hand("AS KS 4S AH QH 3H 7D 6D 5D 4C 3C 2C").quick_tricks(), 3.5);
+        assert_eq!(parse_hand("KS QS AH KD 2D").quick_tricks(), 1.0 + 1.0 + 0.5);
+        assert_eq!(parse_hand("KS 2H 3D 4C").quick_tricks(), 0.0);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_hand_holds_distinct_cards() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = (0..=255).collect::<Vec<u8>>();
+        for start in 0..64 {
+            let hand = Hand::arbitrary(&mut Unstructured::new(&bytes[start..])).unwrap();
+            assert!((1..=HAND_SIZE).contains(&hand.len()));
+            assert_eq!(hand.card_set().len(), hand.len());
+        }
+
+        let hand = Hand::arbitrary(&mut Unstructured::new(&[])).unwrap();
+        assert_eq!(hand.len(), 1);
+    }
+
+    #[test]
+    fn remove_all_counts_cards_removed() {
+        let mut hand = mixed_hand();
+        assert_eq!(hand.remove_all(&parse_cards("AH JH 2D").unwrap()), 0);
+        assert_eq!(hand.len(), 13);
+        assert_eq!(hand.remove_all(&parse_cards("AS JH KH").unwrap()), 2);
+        assert_eq!(hand.len(), 11);
+        assert!(!hand.contains(Card::new(Suit::Spades, Rank::Ace)));
+    }
+
+    #[test]
+    fn void_suits() {
+        let mut hand = parse_hand("AS 7S 2S 10D 3D QC JC");
+        assert!(hand.is_void_in(Suit::Hearts));
+        assert!(!hand.is_void_in(Suit::Diamonds));
+        hand.remove_all(&parse_cards("10D 3D").unwrap());
+        assert!(hand.is_void_in(Suit::Diamonds));
+    }
+
+    #[test]
+    fn try_insert_rejects_duplicates() {
+        let queen_of_spades = Card::new(Suit::Spades, Rank::Queen);
+        let mut hand = Hand::default();
+        assert_eq!(hand.try_insert(queen_of_spades), Ok(()));
+        assert_eq!(
+            hand.try_insert(queen_of_spades),
+            Err(HandError::DuplicateCard(queen_of_spades))
+        );
+        assert_eq!(hand.len(), 1);
+    }
+
+    #[test]
+    fn try_from_iter_reports_first_duplicate() {
+        let cards = parse_cards("AS KH 2D KH AS").unwrap();
+        assert_eq!(
+            Hand::try_from_iter(cards),
+            Err(HandError::DuplicateCard(Card::new(Suit::Hearts, Rank::King)))
+        );
+        assert_eq!(Hand::try_from_iter(mixed_hand().iter()), Ok(mixed_hand()));
+    }
+
+    #[test]
+    fn split_by_color_partitions_black_and_red() {
+        let (black, red) = mixed_hand().split_by_color();
+        assert_eq!(black, parse_cards("5C 6C JC QC 2S 7S AS").unwrap());
+        assert_eq!(red, parse_cards("3D 10D 4H 9H QH KH").unwrap());
+
+        let (black, red) = parse_hand("AS KS").split_by_color();
+        assert_eq!(black.len(), 2);
+        assert!(red.is_empty());
+    }
+
+    #[test]
+    fn bits_round_trip() {
+        let hands = [
+            Hand::default(),
+            mixed_hand(),
+            parse_hand("AS AH AD AC"),
+            parse_hand("2C 3C 4C 5C 6C 7C 8C 9C 10C JC QC KC AC"),
+        ];
+        for hand in hands {
+            let bits = hand.to_bits();
+            assert_eq!(bits.count_ones() as usize, hand.len());
+            assert_eq!(Hand::from_bits(bits), Ok(hand));
+        }
+        assert_eq!(Hand::from_bits(1 << 63 | 1), Ok(parse_hand("2C")));
+    }
+
+    #[test]
+    fn from_bits_rejects_too_many_cards() {
+        assert_eq!(Hand::from_bits((1 << 14) - 1), Err(HandError::TooManyCards));
+        assert_eq!(Hand::from_bits(u64::MAX), Err(HandError::TooManyCards));
+        assert_eq!(Hand::from_bits((1 << 13) - 1).map(|hand| hand.len()), Ok(13));
+    }
+
+    #[test]
+    fn hand_macro_builds_full_hand() {
+        let hand = crate::hand![
+            "AS", "KS", "QS", "2S", "AH", "JH", "9H", "4D", "3D", "2D", "KC", "7C", "6C",
+        ];
+        assert_eq!(hand.len(), 13);
+        assert_eq!(hand.high_card_points(), 4 + 3 + 2 + 4 + 1 + 3);
+        assert_eq!(crate::hand![], Hand::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid card 'ZZ'")]
+    fn hand_macro_panics_on_invalid_token() {
+        let _ = crate::hand!["AS", "ZZ"];
+    }
+
+    #[test]
+    fn spot_card_count_excludes_tens() {
+        assert_eq!(mixed_hand().spot_card_count(), 7);
+        assert_eq!(parse_hand("10S 10H 9D").spot_card_count(), 1);
+        assert_eq!(Hand::default().spot_card_count(), 0);
+    }
+
+    #[test]
+    fn holding_strings() {
+        let hand = parse_hand("AS KS QS AH KH 9H 5H 2H 10D 9D 8D");
+        assert_eq!(hand.holding_string(Suit::Spades, false), "AKQ");
+        assert_eq!(hand.holding_string(Suit::Spades, true), "AKQ");
+        assert_eq!(hand.holding_string(Suit::Hearts, false), "AK952");
+        assert_eq!(hand.holding_string(Suit::Hearts, true), "AKxxx");
+        assert_eq!(hand.holding_string(Suit::Diamonds, true), "Txx");
+        assert_eq!(hand.holding_string(Suit::Clubs, true), "");
+    }
+
+    #[test]
+    fn intersection_and_difference() {
+        let first = parse_hand("AS KS QH 9H 3D");
+        let second = parse_hand("KS QH 2H 3D 4C");
+        assert_eq!(first.intersection(&second), parse_hand("KS QH 3D"));
+        assert_eq!(first.difference(&second), parse_hand("AS 9H"));
+        assert_eq!(second.difference(&first), parse_hand("2H 4C"));
+        assert_eq!(first.intersection(&Hand::default()), Hand::default());
+        assert_eq!(first.difference(&first), Hand::default());
+    }
+
+    #[test]
+    fn cards_slice_is_sorted() {
+        let hand = Card::all().step_by(4).collect::<Hand>();
+        assert_eq!(hand.cards().len(), hand.len());
+        assert_eq!(hand.len(), 13);
+        assert!(hand.cards().windows(2).all(|pair| pair[0] < pair[1]));
+
+        let hand = parse_hand("QH 2C AS 10D");
+        assert_eq!(hand.cards(), parse_cards("2C 10D QH AS").unwrap());
+        assert!(!hand.is_empty());
+        assert!(Hand::default().is_empty());
+        assert_eq!(Hand::default().len(), 0);
+    }
+
+    #[test]
+    fn suit_stats_for_holdings_and_voids() {
+        let hand = mixed_hand();
+        assert_eq!(hand.suit_stats(Suit::Hearts), Some((Rank::Four, Rank::King, 4)));
+        assert_eq!(hand.suit_stats(Suit::Diamonds), Some((Rank::Three, Rank::Ten, 2)));
+        assert_eq!(parse_hand("JS").suit_stats(Suit::Spades), Some((Rank::Jack, Rank::Jack, 1)));
+        assert_eq!(parse_hand("AS KH QD").suit_stats(Suit::Clubs), None);
+    }
+
+    #[test]
+    fn by_suit_ordered_in_both_directions() {
+        let hand = parse_hand("AS 7S KH 10D 3D QC");
+        let ascending = hand.by_suit_ordered(false);
+        let descending = hand.by_suit_ordered(true);
+        assert_eq!(
+            ascending.iter().map(|(suit, _)| *suit).collect::<Vec<_>>(),
+            [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
+        );
+        assert_eq!(descending[0], (Suit::Spades, vec![Rank::Ace, Rank::Seven]));
+        assert_eq!(descending.iter().rev().cloned().collect::<Vec<_>>(), ascending);
+    }
+}
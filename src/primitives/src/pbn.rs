@@ -0,0 +1,169 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversion of deals to and from the deal notation of the Portable Bridge
+//! Notation (PBN) format, e.g.
+//! "N:AKQ.JT9.876.5432 JT9.876.5432.AKQ 876.5432.AKQ.JT9 5432.AKQ.JT9.876".
+//!
+//! A deal is written as the first seat, a colon, and then the four hands in
+//! turn sequence starting from that seat. Each hand lists its Spades, Hearts,
+//! Diamonds, and Clubs separated by '.', and a hand which is not known is
+//! written as '-'.
+
+use core::fmt;
+use std::collections::HashMap;
+
+use enum_iterator::reverse_all;
+
+use crate::hand::{Hand, HandError};
+use crate::{Card, HandIdentifier, ParseCardError, Rank, Suit};
+
+/// Error returned when parsing a deal from PBN notation
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParseError {
+    /// The deal did not begin with a valid seat letter followed by ':'
+    InvalidFirstSeat(String),
+    /// The deal contained the given number of hands instead of four
+    WrongHandCount(usize),
+    /// A hand did not contain exactly four suits separated by '.'
+    InvalidHand(String),
+    /// A rank within a hand could not be parsed
+    InvalidRank(ParseCardError),
+    /// A hand repeated a card or held too many cards
+    InvalidCards(HandError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidFirstSeat(s) => write!(f, "Invalid first seat: '{s}'"),
+            ParseError::WrongHandCount(count) => write!(f, "Expected 4 hands but found {count}"),
+            ParseError::InvalidHand(s) => write!(f, "Invalid hand: '{s}'"),
+            ParseError::InvalidRank(error) => write!(f, "{error}"),
+            ParseError::InvalidCards(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseError::InvalidRank(error) => Some(error),
+            ParseError::InvalidCards(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Writes a deal in PBN notation, starting from North. Seats which are not
+/// present in `hands` are written as '-'.
+pub fn deal_to_pbn(hands: &HashMap<HandIdentifier, Hand>) -> String {
+    let hands = HandIdentifier::iter_from(HandIdentifier::North)
+        .map(|seat| hands.get(&seat).map_or_else(|| "-".to_string(), hand_to_pbn))
+        .collect::<Vec<_>>();
+    format!("N:{}", hands.join(" "))
+}
+
+/// Parses a deal in PBN notation. Hands written as '-' are omitted from the
+/// result.
+pub fn deal_from_pbn(s: &str) -> Result<HashMap<HandIdentifier, Hand>, ParseError> {
+    let (first, hands) =
+        s.trim().split_once(':').ok_or_else(|| ParseError::InvalidFirstSeat(s.to_string()))?;
+    let first = first
+        .parse::<HandIdentifier>()
+        .map_err(|_| ParseError::InvalidFirstSeat(first.to_string()))?;
+
+    let hands = hands.split_whitespace().collect::<Vec<_>>();
+    if hands.len() != 4 {
+        return Err(ParseError::WrongHandCount(hands.len()));
+    }
+
+    let mut result = HashMap::new();
+    for (seat, hand) in HandIdentifier::iter_from(first).zip(hands) {
+        if hand != "-" {
+            result.insert(seat, hand_from_pbn(hand)?);
+        }
+    }
+    Ok(result)
+}
+
+fn hand_to_pbn(hand: &Hand) -> String {
+    let by_suit = hand.by_suit();
+    reverse_all::<Suit>()
+        .map(|suit| {
+            by_suit.get(&suit).map_or_else(String::new, |ranks| {
+                ranks.iter().map(Rank::to_ascii).collect::<String>()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn hand_from_pbn(s: &str) -> Result<Hand, ParseError> {
+    let suits = s.split('.').collect::<Vec<_>>();
+    if suits.len() != 4 {
+        return Err(ParseError::InvalidHand(s.to_string()));
+    }
+
+    let mut cards = vec![];
+    for (suit, ranks) in reverse_all::<Suit>().zip(suits) {
+        for c in ranks.chars() {
+            let rank = Rank::try_from(c).map_err(ParseError::InvalidRank)?;
+            cards.push(Card::new(suit, rank));
+        }
+    }
+    Hand::try_from_iter(cards).map_err(ParseError::InvalidCards)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck;
+
+    const DEAL: &str = "N:AKQJ.T98.765.432 T98.765.432.AKQJ 765.432.AKQJ.T98 432.AKQJ.T98.765";
+
+    #[test]
+    fn known_deal_round_trips() {
+        let hands = deal_from_pbn(DEAL).unwrap();
+        assert_eq!(hands.len(), 4);
+        let honors = [Rank::Ace, Rank::King, Rank::Queen, Rank::Jack];
+        assert_eq!(hands[&HandIdentifier::North].by_suit()[&Suit::Spades], honors);
+        assert_eq!(hands[&HandIdentifier::West].by_suit()[&Suit::Hearts], honors);
+        assert_eq!(deal_to_pbn(&hands), DEAL);
+    }
+
+    #[test]
+    fn generated_deal_round_trips() {
+        let hands = deck::deal_from_permutation(987_654_321).to_map();
+        assert_eq!(deal_from_pbn(&deal_to_pbn(&hands)), Ok(hands));
+    }
+
+    #[test]
+    fn first_seat_rotates_hands() {
+        let hands = deal_from_pbn("E:- AKQJ.T98.765.432 - -").unwrap();
+        assert_eq!(hands.keys().collect::<Vec<_>>(), [&HandIdentifier::South]);
+        assert_eq!(deal_to_pbn(&hands), "N:- - AKQJ.T98.765.432 -");
+    }
+
+    #[test]
+    fn rejects_malformed_deals() {
+        assert_eq!(deal_from_pbn("X:- - - -"), Err(ParseError::InvalidFirstSeat("X".to_string())));
+        assert_eq!(deal_from_pbn("N:- - -"), Err(ParseError::WrongHandCount(3)));
+        assert_eq!(
+            deal_from_pbn("N:AKQ.T98 - - -"),
+            Err(ParseError::InvalidHand("AKQ.T98".to_string()))
+        );
+        assert!(matches!(deal_from_pbn("N:AXQ... - - -"), Err(ParseError::InvalidRank(_))));
+    }
+}
@@ -0,0 +1,62 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for planning declarer play.
+
+/// Returns the number of rounds of trumps which must be played to remove all
+/// trumps from the opponents' hands.
+///
+/// The opponents hold `total_trumps - declarer_trumps - dummy_trumps` trumps,
+/// and each round removes one trump from each opponent who still holds any,
+/// so the number of rounds is the length of the longer opponent holding.
+/// `longest_defender` gives the assumed length of that holding, e.g. Some(4)
+/// to plan for a 4-1 break. If None, the most even split is assumed, so that 5
+/// outstanding trumps break 3-2 and require 3 rounds. Impossible holdings are
+/// clamped to the range of possible breaks.
+pub fn trump_rounds_to_clear(
+    declarer_trumps: u8,
+    dummy_trumps: u8,
+    total_trumps: u8,
+    longest_defender: Option<u8>,
+) -> u8 {
+    let outstanding = total_trumps.saturating_sub(declarer_trumps).saturating_sub(dummy_trumps);
+    let even = outstanding.div_ceil(2);
+    longest_defender.map_or(even, |longest| longest.clamp(even, outstanding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn five_three_fit_with_three_two_split() {
+        assert_eq!(trump_rounds_to_clear(5, 3, 13, None), 3);
+        assert_eq!(trump_rounds_to_clear(5, 3, 13, Some(3)), 3);
+    }
+
+    #[test]
+    fn assumed_bad_breaks() {
+        assert_eq!(trump_rounds_to_clear(5, 3, 13, Some(4)), 4);
+        assert_eq!(trump_rounds_to_clear(5, 3, 13, Some(5)), 5);
+        assert_eq!(trump_rounds_to_clear(4, 4, 13, Some(1)), 3);
+        assert_eq!(trump_rounds_to_clear(5, 3, 13, Some(9)), 5);
+    }
+
+    #[test]
+    fn no_outstanding_trumps() {
+        assert_eq!(trump_rounds_to_clear(7, 6, 13, None), 0);
+        assert_eq!(trump_rounds_to_clear(7, 6, 13, Some(2)), 0);
+        assert_eq!(trump_rounds_to_clear(8, 6, 13, None), 0);
+    }
+}
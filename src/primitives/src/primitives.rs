@@ -12,39 +12,244 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fmt;
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use enum_iterator::Sequence;
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+pub mod bid;
+#[cfg(feature = "std")]
+pub mod canonical;
+pub mod card_set;
+pub mod deal;
+pub mod deck;
+#[cfg(feature = "std")]
+pub mod double_dummy;
+pub mod extended_card;
+pub mod game;
+pub mod hand;
+#[cfg(feature = "std")]
+pub mod pbn;
+pub mod planning;
+pub mod probability;
+pub mod scoring;
+pub mod signals;
+pub mod trick;
+#[cfg(feature = "std")]
+pub mod visibility;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use enum_iterator::{all, Sequence};
 
 /// Represents the four traditional playing card suits.
 ///
 /// Suits are ordered Clubs < Diamonds < Hearts < Spades.
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord)]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Suit {
+    #[default]
     Clubs,
     Diamonds,
     Hearts,
     Spades,
 }
 
+/// Suits display as their symbol, or as their ASCII initial when the alternate
+/// flag is used, e.g. `format!("{:#}", suit)`.
 impl fmt::Display for Suit {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Suit::Clubs => "♣",
-                Suit::Diamonds => "♦",
-                Suit::Hearts => "♥",
-                Suit::Spades => "♠",
-            }
-        )
+        if f.alternate() {
+            return write!(f, "{}", self.to_ascii());
+        }
+
+        write!(f, "{}", self.symbol())
     }
 }
 
-/// Represents the standard playing card ranks, with Aces high
+impl FromStr for Suit {
+    type Err = ParseCardError;
+
+    /// Parses a suit from its symbol (e.g. "♠"), its initial letter (e.g.
+    /// "s"), or its name (e.g. "spades"), ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "clubs" => Ok(Suit::Clubs),
+            "diamonds" => Ok(Suit::Diamonds),
+            "hearts" => Ok(Suit::Hearts),
+            "spades" => Ok(Suit::Spades),
+            other => single_char(other)
+                .and_then(|c| Suit::try_from(c).ok())
+                .ok_or_else(|| ParseCardError::UnknownSuit(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<char> for Suit {
+    type Error = ParseCardError;
+
+    /// Converts a suit symbol (e.g. '♠') or initial letter (e.g. 's'),
+    /// ignoring case.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_lowercase() {
+            '♣' | 'c' => Ok(Suit::Clubs),
+            '♦' | 'd' => Ok(Suit::Diamonds),
+            '♥' | 'h' => Ok(Suit::Hearts),
+            '♠' | 's' => Ok(Suit::Spades),
+            _ => Err(ParseCardError::UnknownSuit(c.to_string())),
+        }
+    }
+}
+
+impl Suit {
+    /// All suits, in the same order as [enum_iterator::all]
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    /// Returns the position of this suit in declaration order, from Clubs = 0
+    /// to Spades = 3.
+    pub const fn to_index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the suit for an index produced by [Self::to_index], or None if
+    /// the index is greater than 3.
+    pub const fn from_index(index: u8) -> Option<Suit> {
+        if (index as usize) < Self::ALL.len() {
+            Some(Self::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Unicode symbol for this suit, e.g. '♠'
+    pub fn symbol(&self) -> char {
+        match self {
+            Suit::Clubs => '♣',
+            Suit::Diamonds => '♦',
+            Suit::Hearts => '♥',
+            Suit::Spades => '♠',
+        }
+    }
+
+    /// Returns the uppercase ASCII initial of this suit's name
+    pub fn to_ascii(&self) -> char {
+        match self {
+            Suit::Clubs => 'C',
+            Suit::Diamonds => 'D',
+            Suit::Hearts => 'H',
+            Suit::Spades => 'S',
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self {
+            Suit::Clubs | Suit::Spades => Color::Black,
+            Suit::Diamonds | Suit::Hearts => Color::Red,
+        }
+    }
+
+    /// True for the major suits, Hearts and Spades
+    pub fn is_major(&self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Spades)
+    }
+
+    /// True for the minor suits, Clubs and Diamonds
+    pub fn is_minor(&self) -> bool {
+        !self.is_major()
+    }
+
+    /// Returns the suit `n` steps after this one in the cycle Clubs, Diamonds,
+    /// Hearts, Spades, wrapping back around to Clubs.
+    pub fn rotate(&self, n: u8) -> Suit {
+        Self::ALL[(self.to_index() as usize + n as usize) % Self::ALL.len()]
+    }
+
+    /// Iterates over all four suits in [enum_iterator::all] order beginning
+    /// with `start` and wrapping around, e.g. Hearts, Spades, Clubs, Diamonds.
+    pub fn cycle_from(start: Suit) -> impl Iterator<Item = Suit> {
+        (0..Self::ALL.len() as u8).map(move |n| start.rotate(n))
+    }
+}
+
+/// The color in which a [Suit] is printed
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Color {
+    Red,
+    Black,
+}
+
+/// The denomination of a contract: either a trump suit or no-trump.
+///
+/// Strains are ordered by bidding rank, Clubs < Diamonds < Hearts < Spades <
+/// NoTrump.
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Strain {
+    Clubs,
+    Diamonds,
+    Hearts,
+    Spades,
+    NoTrump,
+}
+
+impl fmt::Display for Strain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.trump_suit() {
+            Some(suit) => suit.fmt(f),
+            None => write!(f, "NT"),
+        }
+    }
+}
+
+impl From<Suit> for Strain {
+    fn from(suit: Suit) -> Self {
+        match suit {
+            Suit::Clubs => Strain::Clubs,
+            Suit::Diamonds => Strain::Diamonds,
+            Suit::Hearts => Strain::Hearts,
+            Suit::Spades => Strain::Spades,
+        }
+    }
+}
+
+impl Strain {
+    /// Returns the trump suit for a contract in this strain, or None for
+    /// no-trump.
+    pub fn trump_suit(&self) -> Option<Suit> {
+        match self {
+            Strain::Clubs => Some(Suit::Clubs),
+            Strain::Diamonds => Some(Suit::Diamonds),
+            Strain::Hearts => Some(Suit::Hearts),
+            Strain::Spades => Some(Suit::Spades),
+            Strain::NoTrump => None,
+        }
+    }
+
+    /// True for no-trump, where no suit outranks the suit led
+    pub fn is_no_trump(&self) -> bool {
+        *self == Strain::NoTrump
+    }
+
+    /// True if `suit` is the trump suit in this strain
+    pub fn is_trump(&self, suit: Suit) -> bool {
+        self.trump_suit() == Some(suit)
+    }
+}
+
+/// Represents the standard playing card ranks, with Aces high
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, PartialOrd, Ord, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum Rank {
+    #[default]
     Two,
     Three,
     Four,
@@ -84,30 +289,437 @@ impl fmt::Display for Rank {
     }
 }
 
+impl FromStr for Rank {
+    type Err = ParseCardError;
+
+    /// Parses a rank from its symbol (e.g. "K") or its name (e.g. "king"),
+    /// ignoring case. Tens may be written as either "10" or "T".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "two" => Ok(Rank::Two),
+            "three" => Ok(Rank::Three),
+            "four" => Ok(Rank::Four),
+            "five" => Ok(Rank::Five),
+            "six" => Ok(Rank::Six),
+            "seven" => Ok(Rank::Seven),
+            "eight" => Ok(Rank::Eight),
+            "nine" => Ok(Rank::Nine),
+            "10" | "ten" => Ok(Rank::Ten),
+            "jack" => Ok(Rank::Jack),
+            "queen" => Ok(Rank::Queen),
+            "king" => Ok(Rank::King),
+            "ace" => Ok(Rank::Ace),
+            other => single_char(other)
+                .and_then(|c| Rank::try_from(c).ok())
+                .ok_or_else(|| ParseCardError::UnknownRank(s.to_string())),
+        }
+    }
+}
+
+impl TryFrom<char> for Rank {
+    type Error = ParseCardError;
+
+    /// Converts a single-character rank symbol, ignoring case. Tens must be
+    /// written as 'T', since '1' on its own is ambiguous.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_ascii_lowercase() {
+            '2' => Ok(Rank::Two),
+            '3' => Ok(Rank::Three),
+            '4' => Ok(Rank::Four),
+            '5' => Ok(Rank::Five),
+            '6' => Ok(Rank::Six),
+            '7' => Ok(Rank::Seven),
+            '8' => Ok(Rank::Eight),
+            '9' => Ok(Rank::Nine),
+            't' => Ok(Rank::Ten),
+            'j' => Ok(Rank::Jack),
+            'q' => Ok(Rank::Queen),
+            'k' => Ok(Rank::King),
+            'a' => Ok(Rank::Ace),
+            _ => Err(ParseCardError::UnknownRank(c.to_string())),
+        }
+    }
+}
+
+impl Rank {
+    /// All ranks, in the same order as [enum_iterator::all]
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    /// Returns the position of this rank in declaration order, from Two = 0 to
+    /// Ace = 12.
+    pub const fn to_index(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the rank for an index produced by [Self::to_index], or None if
+    /// the index is greater than 12.
+    pub const fn from_index(index: u8) -> Option<Rank> {
+        if (index as usize) < Self::ALL.len() {
+            Some(Self::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a single ASCII character for this rank, using 'T' for Tens.
+    pub fn to_ascii(&self) -> char {
+        match self {
+            Rank::Two => '2',
+            Rank::Three => '3',
+            Rank::Four => '4',
+            Rank::Five => '5',
+            Rank::Six => '6',
+            Rank::Seven => '7',
+            Rank::Eight => '8',
+            Rank::Nine => '9',
+            Rank::Ten => 'T',
+            Rank::Jack => 'J',
+            Rank::Queen => 'Q',
+            Rank::King => 'K',
+            Rank::Ace => 'A',
+        }
+    }
+
+    /// Returns true for the honor cards, Ten through Ace.
+    ///
+    /// Some variants only treat Jack through Ace as honors. This follows the
+    /// bridge convention of including the Ten.
+    pub fn is_honor(&self) -> bool {
+        *self >= Rank::Ten
+    }
+
+    /// Returns whether this rank is an honor or a spot card, see
+    /// [Self::is_honor]
+    pub fn category(&self) -> RankCategory {
+        if self.is_honor() {
+            RankCategory::Honor
+        } else {
+            RankCategory::Spot
+        }
+    }
+
+    /// Returns the high card points for this rank under the standard 4-3-2-1
+    /// count: 4 for an Ace, 3 for a King, 2 for a Queen, 1 for a Jack, and 0
+    /// otherwise.
+    pub fn points_hcp(&self) -> u8 {
+        match self {
+            Rank::Ace => 4,
+            Rank::King => 3,
+            Rank::Queen => 2,
+            Rank::Jack => 1,
+            _ => 0,
+        }
+    }
+
+    /// Returns the rank worth `points` high card points, the inverse of
+    /// [Self::points_hcp]. Returns None for 0, which applies to every rank
+    /// below Jack, and for values above 4.
+    pub fn from_hcp(points: u8) -> Option<Rank> {
+        match points {
+            4 => Some(Rank::Ace),
+            3 => Some(Rank::King),
+            2 => Some(Rank::Queen),
+            1 => Some(Rank::Jack),
+            _ => None,
+        }
+    }
+
+    /// Returns the next higher rank, or None for Aces.
+    pub fn successor(&self) -> Option<Rank> {
+        Sequence::next(self)
+    }
+
+    /// Returns the next lower rank, or None for Twos.
+    pub fn predecessor(&self) -> Option<Rank> {
+        Sequence::previous(self)
+    }
+
+    /// Returns the number of steps from this rank up to `other`. The result is
+    /// negative if `other` is lower than this rank.
+    pub fn distance(&self, other: &Rank) -> i8 {
+        *other as i8 - *self as i8
+    }
+
+    /// Compares two ranks treating Aces as lower than Twos, as in variants
+    /// where the Ace plays low. All other ranks compare normally.
+    pub fn cmp_ace_low(&self, other: &Rank) -> Ordering {
+        self.ace_low_value().cmp(&other.ace_low_value())
+    }
+
+    fn ace_low_value(&self) -> u8 {
+        match self {
+            Rank::Ace => 0,
+            _ => *self as u8 + 1,
+        }
+    }
+}
+
+/// Broad grouping of a [Rank], see [Rank::category]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RankCategory {
+    /// Two through Nine
+    Spot,
+    /// Ten through Ace
+    Honor,
+}
+
+/// Wrapper around a [Rank] which orders Aces below Twos, see
+/// [Rank::cmp_ace_low].
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct RankAceLow(pub Rank);
+
+impl PartialOrd for RankAceLow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankAceLow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.cmp_ace_low(&other.0)
+    }
+}
+
 /// Represents one of the 52 standard playing cards. Card ordering is by [Suit]
 /// first and then by [Rank].
 #[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, PartialOrd, Ord)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub struct Card {
     pub suit: Suit,
     pub rank: Rank,
 }
 
+/// Cards display as their rank followed by their suit. The alternate flag is
+/// passed through to the suit.
 impl fmt::Display for Card {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.rank, self.suit)
+        if f.alternate() {
+            write!(f, "{}{:#}", self.rank, self.suit)
+        } else {
+            write!(f, "{}{}", self.rank, self.suit)
+        }
+    }
+}
+
+impl FromStr for Card {
+    type Err = ParseCardError;
+
+    /// Parses a card written either compactly as a rank followed by a suit
+    /// (e.g. "AS", "10h", "Q♦") or in words with an optional "of" (e.g. "King
+    /// of Spades", "ten clubs"), ignoring case.
+    ///
+    /// This accepts the output of the [Card] `Display` implementation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let lowercase = trimmed.to_lowercase();
+        if let [rank, "of", suit] | [rank, suit] =
+            lowercase.split_whitespace().collect::<Vec<_>>().as_slice()
+        {
+            return Ok(Card::new(suit.parse()?, rank.parse()?));
+        }
+
+        if !(2..=3).contains(&trimmed.chars().count()) {
+            return Err(ParseCardError::InvalidLength(s.to_string()));
+        }
+        let (suit_start, _) = trimmed.char_indices().last().expect("Input is not empty");
+        let (rank, suit) = trimmed.split_at(suit_start);
+        Ok(Card::new(suit.parse()?, rank.parse()?))
+    }
+}
+
+/// Cards serialize compactly as a string of their rank followed by the ASCII
+/// initial of their suit, e.g. "AS" or "10H".
+#[cfg(feature = "serde")]
+impl serde::Serialize for Card {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{self:#}"))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Card {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<(Suit, Rank)> for Card {
+    fn from((suit, rank): (Suit, Rank)) -> Self {
+        Self::new(suit, rank)
     }
 }
 
 impl Card {
-    pub fn new(suit: Suit, rank: Rank) -> Self {
+    pub const fn new(suit: Suit, rank: Rank) -> Self {
         Self { suit, rank }
     }
+
+    /// Returns this card's suit and rank as a tuple, for destructuring
+    pub const fn suit_rank_tuple(&self) -> (Suit, Rank) {
+        (self.suit, self.rank)
+    }
+
+    /// Iterates over all 52 cards in ascending [Card] order, i.e. by [Suit]
+    /// and then by [Rank].
+    pub fn all() -> impl Iterator<Item = Card> {
+        all::<Suit>().flat_map(|suit| all::<Rank>().map(move |rank| Card::new(suit, rank)))
+    }
+
+    /// Iterates over all 52 cards ordered by [Rank] and then by [Suit], i.e.
+    /// starting with the four Twos from Clubs to Spades and ending with the
+    /// four Aces.
+    pub fn all_rank_major() -> impl Iterator<Item = Card> {
+        all::<Rank>().flat_map(|rank| all::<Suit>().map(move |suit| Card::new(suit, rank)))
+    }
+
+    /// Returns the rank and suit glyphs used to draw this card, i.e. the
+    /// [Rank] as displayed ("10" for a Ten) and [Suit::symbol].
+    pub fn glyphs(&self) -> (String, char) {
+        (self.rank.to_string(), self.suit.symbol())
+    }
+
+    /// Returns this card with its suit rotated by `n` steps, see
+    /// [Suit::rotate]. The rank is unchanged.
+    pub fn rotate_suit(&self, n: u8) -> Card {
+        Card::new(self.suit.rotate(n), self.rank)
+    }
+
+    /// Returns true if this card has the same rank as `other`, in any suit
+    pub fn same_rank(&self, other: &Card) -> bool {
+        self.rank == other.rank
+    }
+
+    /// Returns true if this card has the same suit as `other`, of any rank
+    pub fn same_suit(&self, other: &Card) -> bool {
+        self.suit == other.suit
+    }
+
+    /// Returns true if this card would beat `other` when both are played to
+    /// the same trick played in the `trump` strain.
+    ///
+    /// A trump beats any card which is not a trump, and cards of the same suit
+    /// compare by rank. Cards of two different non-trump suits cannot be
+    /// compared without knowing which suit was led, so this returns false in
+    /// both directions. This is therefore a partial relationship and not a
+    /// total order.
+    pub fn beats(&self, other: &Card, trump: Strain) -> bool {
+        if self.suit == other.suit {
+            self.rank > other.rank
+        } else {
+            trump.is_trump(self.suit)
+        }
+    }
+
+    /// Returns a compact numeric encoding of this card in the range 0-51.
+    ///
+    /// The encoding is `suit * 13 + rank`, using [Suit::to_index] and
+    /// [Rank::to_index]. This encoding is stable and may be persisted, and
+    /// index order matches the ordering of [Card].
+    pub const fn to_index(&self) -> u8 {
+        self.suit.to_index() * 13 + self.rank.to_index()
+    }
+
+    /// Returns the card for an encoding produced by [Self::to_index], or None
+    /// if the index is greater than 51.
+    ///
+    /// This is a `const fn`, so it can be used to build static card tables.
+    pub const fn from_index(index: u8) -> Option<Self> {
+        match (Suit::from_index(index / 13), Rank::from_index(index % 13)) {
+            (Some(suit), Some(rank)) => Some(Self::new(suit, rank)),
+            _ => None,
+        }
+    }
+}
+
+/// Invokes `f` once for each of the 52 cards in [Card::all] order, for
+/// exhaustively checking rank- and suit-dependent logic in tests.
+pub fn for_all_cards(f: impl FnMut(Card)) {
+    Card::all().for_each(f)
+}
+
+/// Error returned when parsing a [Card], [Suit], or [Rank] from a string.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParseCardError {
+    /// The rank portion of the input was not recognized
+    UnknownRank(String),
+    /// The suit portion of the input was not recognized
+    UnknownSuit(String),
+    /// The input was too short or too long to describe a card
+    InvalidLength(String),
+    /// The token at `index` in a list of cards could not be parsed
+    InvalidToken { index: usize, error: Box<ParseCardError> },
+}
+
+impl fmt::Display for ParseCardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseCardError::UnknownRank(s) => write!(f, "Unknown rank: '{s}'"),
+            ParseCardError::UnknownSuit(s) => write!(f, "Unknown suit: '{s}'"),
+            ParseCardError::InvalidLength(s) => write!(f, "Invalid card length: '{s}'"),
+            ParseCardError::InvalidToken { index, error } => {
+                write!(f, "Invalid card at position {index}: {error}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseCardError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ParseCardError::InvalidToken { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Parses a whitespace-separated list of cards in compact form, e.g. "AS KH
+/// 3D".
+///
+/// If any token fails to parse, returns [ParseCardError::InvalidToken] with the
+/// zero-based position of the first invalid token.
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, ParseCardError> {
+    s.split_whitespace()
+        .enumerate()
+        .map(|(index, token)| {
+            token
+                .parse()
+                .map_err(|error| ParseCardError::InvalidToken { index, error: Box::new(error) })
+        })
+        .collect()
+}
+
+/// Returns the only character in `s`, or None if `s` does not contain exactly
+/// one character.
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
 }
 
 /// Represents one of the four hands in an Oak game.
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, Ord, PartialOrd)]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, Ord, PartialOrd, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum HandIdentifier {
     /// Dummy partner of human player
+    #[default]
     North,
     /// Dummy partner of AI player
     East,
@@ -117,7 +729,47 @@ pub enum HandIdentifier {
     West,
 }
 
+impl fmt::Display for HandIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::North => "North",
+                Self::East => "East",
+                Self::South => "South",
+                Self::West => "West",
+            }
+        )
+    }
+}
+
+impl FromStr for HandIdentifier {
+    type Err = ParseIdentifierError;
+
+    /// Parses a position from its name (e.g. "North") or initial (e.g. "N"),
+    /// ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "n" | "north" => Ok(Self::North),
+            "e" | "east" => Ok(Self::East),
+            "s" | "south" => Ok(Self::South),
+            "w" | "west" => Ok(Self::West),
+            _ => Err(ParseIdentifierError::UnknownHandIdentifier(s.to_string())),
+        }
+    }
+}
+
 impl HandIdentifier {
+    /// All positions, in the same order as [enum_iterator::all]
+    pub const ALL: [HandIdentifier; 4] =
+        [HandIdentifier::North, HandIdentifier::East, HandIdentifier::South, HandIdentifier::West];
+
+    /// Returns a stable ordinal for this position, for use in serialized data.
+    pub const fn ordinal(&self) -> u8 {
+        *self as u8
+    }
+
     /// Returns the next position in turn sequence after this one
     pub fn next(&self) -> Self {
         match self {
@@ -128,6 +780,23 @@ impl HandIdentifier {
         }
     }
 
+    /// Returns the previous position in turn sequence before this one, the
+    /// inverse of [Self::next]
+    pub fn prev(&self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    /// Iterates over all four positions in turn sequence, beginning with
+    /// `start`.
+    pub fn iter_from(start: HandIdentifier) -> impl Iterator<Item = HandIdentifier> {
+        core::iter::successors(Some(start), |hand| Some(hand.next())).take(4)
+    }
+
     /// Returns the partner position of this position
     pub fn partner(&self) -> Self {
         match self {
@@ -138,6 +807,26 @@ impl HandIdentifier {
         }
     }
 
+    /// Returns the two positions of the opposing partnership, starting with
+    /// the one which plays immediately after this position.
+    pub fn opponents(&self) -> [HandIdentifier; 2] {
+        [self.next(), self.partner().next()]
+    }
+
+    /// Returns the dummy position for a round in which `declarer` is the
+    /// declaring position, i.e. the declarer's partner.
+    pub fn dummy_of(declarer: HandIdentifier) -> HandIdentifier {
+        declarer.partner()
+    }
+
+    /// Returns the partnership this position belongs to
+    pub fn partnership(&self) -> Partnership {
+        match self {
+            Self::North | Self::South => Partnership::NorthSouth,
+            Self::East | Self::West => Partnership::EastWest,
+        }
+    }
+
     pub fn player_name(&self) -> PlayerName {
         match self {
             Self::South | Self::North => PlayerName::User,
@@ -147,13 +836,47 @@ impl HandIdentifier {
 }
 
 /// Identifies one of the two players participating in a round
-#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence)]
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum PlayerName {
+    #[default]
     User,
     Opponent,
 }
 
+impl fmt::Display for PlayerName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                PlayerName::User => "User",
+                PlayerName::Opponent => "Opponent",
+            }
+        )
+    }
+}
+
+impl FromStr for PlayerName {
+    type Err = ParseIdentifierError;
+
+    /// Parses a player from its name, "User" or "Opponent", ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "user" => Ok(PlayerName::User),
+            "opponent" => Ok(PlayerName::Opponent),
+            _ => Err(ParseIdentifierError::UnknownPlayerName(s.to_string())),
+        }
+    }
+}
+
 impl PlayerName {
+    /// Returns a stable ordinal for this player, for use in serialized data.
+    pub const fn ordinal(&self) -> u8 {
+        *self as u8
+    }
+
     /// Returns the hand which this player can see at the beginning of the
     /// auction phase.
     ///
@@ -165,4 +888,715 @@ impl PlayerName {
             PlayerName::Opponent => HandIdentifier::West,
         }
     }
+
+    /// Returns the other player in the round
+    pub fn opponent(&self) -> PlayerName {
+        match self {
+            PlayerName::User => PlayerName::Opponent,
+            PlayerName::Opponent => PlayerName::User,
+        }
+    }
+
+    /// Returns true if this player controls the `declarer` position, and thus
+    /// also its dummy.
+    pub fn is_declaring_side(&self, declarer: HandIdentifier) -> bool {
+        declarer.player_name() == *self
+    }
+}
+
+/// Error returned when parsing a [HandIdentifier] or [PlayerName] from a
+/// string.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum ParseIdentifierError {
+    UnknownHandIdentifier(String),
+    UnknownPlayerName(String),
+}
+
+impl fmt::Display for ParseIdentifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIdentifierError::UnknownHandIdentifier(s) => write!(f, "Unknown position: '{s}'"),
+            ParseIdentifierError::UnknownPlayerName(s) => write!(f, "Unknown player: '{s}'"),
+        }
+    }
+}
+
+impl core::error::Error for ParseIdentifierError {}
+
+/// Identifies one of the two pairs of partner positions at the table
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Sequence)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Partnership {
+    NorthSouth,
+    EastWest,
+}
+
+impl Partnership {
+    /// Returns the two positions in this partnership
+    pub fn seats(&self) -> [HandIdentifier; 2] {
+        match self {
+            Partnership::NorthSouth => [HandIdentifier::North, HandIdentifier::South],
+            Partnership::EastWest => [HandIdentifier::East, HandIdentifier::West],
+        }
+    }
+}
+
+// Serialized data depends on these values, so they are pinned by compile-time
+// assertions: reordering enum variants fails the build rather than silently
+// changing them.
+const _: () = {
+    assert!(Suit::Clubs.to_index() == 0);
+    assert!(Suit::Diamonds.to_index() == 1);
+    assert!(Suit::Hearts.to_index() == 2);
+    assert!(Suit::Spades.to_index() == 3);
+
+    assert!(Rank::Two.to_index() == 0);
+    assert!(Rank::Three.to_index() == 1);
+    assert!(Rank::Four.to_index() == 2);
+    assert!(Rank::Five.to_index() == 3);
+    assert!(Rank::Six.to_index() == 4);
+    assert!(Rank::Seven.to_index() == 5);
+    assert!(Rank::Eight.to_index() == 6);
+    assert!(Rank::Nine.to_index() == 7);
+    assert!(Rank::Ten.to_index() == 8);
+    assert!(Rank::Jack.to_index() == 9);
+    assert!(Rank::Queen.to_index() == 10);
+    assert!(Rank::King.to_index() == 11);
+    assert!(Rank::Ace.to_index() == 12);
+
+    assert!(HandIdentifier::North.ordinal() == 0);
+    assert!(HandIdentifier::East.ordinal() == 1);
+    assert!(HandIdentifier::South.ordinal() == 2);
+    assert!(HandIdentifier::West.ordinal() == 3);
+
+    assert!(PlayerName::User.ordinal() == 0);
+    assert!(PlayerName::Opponent.ordinal() == 1);
+};
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn card_display_round_trips_through_from_str() {
+        for card in Card::all() {
+            assert_eq!(card.to_string().parse::<Card>(), Ok(card));
+        }
+    }
+
+    #[test]
+    fn card_from_str_accepts_flexible_formats() {
+        let ace_of_spades = Card::new(Suit::Spades, Rank::Ace);
+        assert_eq!("AS".parse(), Ok(ace_of_spades));
+        assert_eq!("as".parse(), Ok(ace_of_spades));
+        assert_eq!("A♠".parse(), Ok(ace_of_spades));
+        assert_eq!("KING OF SPADES".parse(), Ok(Card::new(Suit::Spades, Rank::King)));
+        assert_eq!("Qd".parse(), Ok(Card::new(Suit::Diamonds, Rank::Queen)));
+        assert_eq!("10H".parse(), Ok(Card::new(Suit::Hearts, Rank::Ten)));
+        assert_eq!("t♣".parse(), Ok(Card::new(Suit::Clubs, Rank::Ten)));
+    }
+
+    #[test]
+    fn card_from_str_reports_errors() {
+        assert_eq!("1S".parse::<Card>(), Err(ParseCardError::UnknownRank("1".to_string())));
+        assert_eq!("AX".parse::<Card>(), Err(ParseCardError::UnknownSuit("X".to_string())));
+        assert_eq!("A".parse::<Card>(), Err(ParseCardError::InvalidLength("A".to_string())));
+        assert_eq!("10SS".parse::<Card>(), Err(ParseCardError::InvalidLength("10SS".to_string())));
+    }
+
+    #[test]
+    fn suit_and_rank_from_str() {
+        assert_eq!("♥".parse(), Ok(Suit::Hearts));
+        assert_eq!("C".parse(), Ok(Suit::Clubs));
+        assert_eq!("T".parse(), Ok(Rank::Ten));
+        assert_eq!("10".parse(), Ok(Rank::Ten));
+        assert_eq!("j".parse(), Ok(Rank::Jack));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn card_serializes_as_compact_string() {
+        let card = Card::new(Suit::Hearts, Rank::Ten);
+        assert_eq!(serde_json::to_string(&card).unwrap(), r#""10H""#);
+        assert_eq!(serde_json::from_str::<Card>(r#""10H""#).unwrap(), card);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn card_serialization_is_stable() {
+        for card in Card::all() {
+            let bytes = serde_json::to_vec(&card).unwrap();
+            let parsed = serde_json::from_slice::<Card>(&bytes).unwrap();
+            assert_eq!(parsed, card);
+            assert_eq!(serde_json::to_vec(&parsed).unwrap(), bytes);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn enums_serialize_by_variant_name() {
+        assert_eq!(serde_json::to_string(&Suit::Spades).unwrap(), r#""Spades""#);
+        assert_eq!(serde_json::to_string(&Rank::Queen).unwrap(), r#""Queen""#);
+        assert_eq!(serde_json::to_string(&HandIdentifier::North).unwrap(), r#""North""#);
+        assert_eq!(serde_json::to_string(&PlayerName::Opponent).unwrap(), r#""Opponent""#);
+        assert_eq!(
+            serde_json::from_str::<HandIdentifier>(r#""West""#).unwrap(),
+            HandIdentifier::West
+        );
+    }
+
+    #[test]
+    fn card_index_round_trips() {
+        for index in 0..52 {
+            let card = Card::from_index(index).expect("Valid index");
+            assert_eq!(card.to_index(), index);
+            assert_eq!(index, card.suit.to_index() * 13 + card.rank.to_index());
+        }
+    }
+
+    #[test]
+    fn card_from_index_rejects_out_of_range() {
+        for index in 52..=255 {
+            assert_eq!(Card::from_index(index), None);
+        }
+    }
+
+    #[test]
+    fn card_index_order_matches_card_order() {
+        let cards = (0..52).map(|index| Card::from_index(index).unwrap()).collect::<Vec<_>>();
+        assert!(cards.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(Card::new(Suit::Clubs, Rank::Two).to_index(), 0);
+        assert_eq!(Card::new(Suit::Spades, Rank::Ace).to_index(), 51);
+    }
+
+    #[test]
+    fn card_all_yields_52_increasing_cards() {
+        let cards = Card::all().collect::<Vec<_>>();
+        assert_eq!(cards.len(), 52);
+        assert!(cards.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn rank_successor_and_predecessor() {
+        assert_eq!(Rank::Ace.successor(), None);
+        assert_eq!(Rank::Two.predecessor(), None);
+        assert_eq!(Rank::Nine.successor(), Some(Rank::Ten));
+        assert_eq!(Rank::Ten.predecessor(), Some(Rank::Nine));
+    }
+
+    #[test]
+    fn rank_distance_is_antisymmetric() {
+        assert_eq!(Rank::Two.distance(&Rank::Ace), 12);
+        assert_eq!(Rank::King.distance(&Rank::Queen), -1);
+        for a in all::<Rank>() {
+            for b in all::<Rank>() {
+                assert_eq!(a.distance(&b), -b.distance(&a));
+            }
+        }
+    }
+
+    #[test]
+    fn ace_low_ordering() {
+        assert_eq!(Rank::Ace.cmp_ace_low(&Rank::Two), Ordering::Less);
+        assert_eq!(Rank::Ace.cmp(&Rank::King), Ordering::Greater);
+        assert_eq!(Rank::King.cmp_ace_low(&Rank::Two), Ordering::Greater);
+
+        let mut ranks = [Rank::King, Rank::Ace, Rank::Two].map(RankAceLow);
+        ranks.sort();
+        assert_eq!(ranks.map(|rank| rank.0), [Rank::Ace, Rank::Two, Rank::King]);
+    }
+
+    #[test]
+    fn suit_colors() {
+        assert_eq!(Suit::Clubs.color(), Color::Black);
+        assert_eq!(Suit::Diamonds.color(), Color::Red);
+        assert_eq!(Suit::Hearts.color(), Color::Red);
+        assert_eq!(Suit::Spades.color(), Color::Black);
+    }
+
+    #[test]
+    fn major_and_minor_suits() {
+        assert_eq!(
+            all::<Suit>().map(|suit| suit.is_major()).collect::<Vec<_>>(),
+            [false, false, true, true]
+        );
+        assert_eq!(
+            all::<Suit>().map(|suit| suit.is_minor()).collect::<Vec<_>>(),
+            [true, true, false, false]
+        );
+    }
+
+    #[test]
+    fn suit_display_modes() {
+        let rendered =
+            all::<Suit>().map(|suit| (format!("{suit}"), format!("{suit:#}"))).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            [
+                ("♣".to_string(), "C".to_string()),
+                ("♦".to_string(), "D".to_string()),
+                ("♥".to_string(), "H".to_string()),
+                ("♠".to_string(), "S".to_string()),
+            ]
+        );
+        assert_eq!(Suit::Hearts.to_ascii(), 'H');
+    }
+
+    #[test]
+    fn card_display_passes_alternate_flag_to_suit() {
+        let card = Card::new(Suit::Diamonds, Rank::Ten);
+        assert_eq!(format!("{card}"), "10♦");
+        assert_eq!(format!("{card:#}"), "10D");
+    }
+
+    #[test]
+    fn iter_from_yields_turn_order() {
+        use HandIdentifier::*;
+        assert_eq!(HandIdentifier::iter_from(West).collect::<Vec<_>>(), [West, North, East, South]);
+        assert_eq!(
+            HandIdentifier::iter_from(North).collect::<Vec<_>>(),
+            [North, East, South, West]
+        );
+    }
+
+    #[test]
+    fn dummy_and_declaring_side() {
+        use HandIdentifier::*;
+        assert_eq!(HandIdentifier::dummy_of(South), North);
+        assert_eq!(HandIdentifier::dummy_of(East), West);
+        assert!(PlayerName::User.is_declaring_side(North));
+        assert!(PlayerName::User.is_declaring_side(South));
+        assert!(!PlayerName::User.is_declaring_side(West));
+        assert!(PlayerName::Opponent.is_declaring_side(East));
+        assert!(!PlayerName::Opponent.is_declaring_side(South));
+    }
+
+    #[test]
+    fn opponents_of_each_seat_and_player() {
+        use HandIdentifier::*;
+        assert_eq!(North.opponents(), [East, West]);
+        assert_eq!(East.opponents(), [South, North]);
+        assert_eq!(South.opponents(), [West, East]);
+        assert_eq!(West.opponents(), [North, South]);
+        assert_eq!(PlayerName::User.opponent(), PlayerName::Opponent);
+        assert_eq!(PlayerName::Opponent.opponent(), PlayerName::User);
+    }
+
+    #[test]
+    fn rank_and_suit_index_round_trip() {
+        for rank in all::<Rank>() {
+            assert_eq!(Rank::from_index(rank.to_index()), Some(rank));
+        }
+        for suit in all::<Suit>() {
+            assert_eq!(Suit::from_index(suit.to_index()), Some(suit));
+        }
+        assert_eq!(Rank::Two.to_index(), 0);
+        assert_eq!(Rank::Ace.to_index(), 12);
+        assert_eq!(Suit::Clubs.to_index(), 0);
+        assert_eq!(Suit::Spades.to_index(), 3);
+    }
+
+    #[test]
+    fn rank_and_suit_from_index_rejects_out_of_range() {
+        assert_eq!(Rank::from_index(13), None);
+        assert_eq!(Rank::from_index(u8::MAX), None);
+        assert_eq!(Suit::from_index(4), None);
+        assert_eq!(Suit::from_index(u8::MAX), None);
+    }
+
+    #[test]
+    fn enum_defaults_are_first_variants() {
+        assert_eq!(Suit::default(), Suit::Clubs);
+        assert_eq!(Rank::default(), Rank::Two);
+        assert_eq!(HandIdentifier::default(), HandIdentifier::North);
+        assert_eq!(PlayerName::default(), PlayerName::User);
+        assert_eq!(Some(Suit::default()), enum_iterator::first::<Suit>());
+    }
+
+    #[test]
+    fn card_beats() {
+        let card = |s: &str| s.parse::<Card>().unwrap();
+        assert!(card("2S").beats(&card("AH"), Strain::Spades));
+        assert!(!card("AH").beats(&card("2S"), Strain::Spades));
+        assert!(card("KH").beats(&card("QH"), Strain::NoTrump));
+        assert!(!card("QH").beats(&card("KH"), Strain::Hearts));
+        assert!(!card("AH").beats(&card("2D"), Strain::Clubs));
+        assert!(!card("2D").beats(&card("AH"), Strain::Clubs));
+        assert!(!card("AH").beats(&card("2D"), Strain::NoTrump));
+    }
+
+    #[test]
+    fn suit_try_from_char() {
+        assert_eq!(Suit::try_from('c'), Ok(Suit::Clubs));
+        assert_eq!(Suit::try_from('D'), Ok(Suit::Diamonds));
+        assert_eq!(Suit::try_from('♥'), Ok(Suit::Hearts));
+        assert_eq!(Suit::try_from('♠'), Ok(Suit::Spades));
+        assert_eq!(Suit::try_from('x'), Err(ParseCardError::UnknownSuit("x".to_string())));
+    }
+
+    #[test]
+    fn rank_try_from_char() {
+        assert_eq!(Rank::try_from('2'), Ok(Rank::Two));
+        assert_eq!(Rank::try_from('9'), Ok(Rank::Nine));
+        assert_eq!(Rank::try_from('T'), Ok(Rank::Ten));
+        assert_eq!(Rank::try_from('q'), Ok(Rank::Queen));
+        assert_eq!(Rank::try_from('A'), Ok(Rank::Ace));
+        assert_eq!(Rank::try_from('1'), Err(ParseCardError::UnknownRank("1".to_string())));
+        assert_eq!(Rank::try_from('0'), Err(ParseCardError::UnknownRank("0".to_string())));
+    }
+
+    const ACE_OF_SPADES: Card = Card::new(Suit::Spades, Rank::Ace);
+
+    static CARD_TABLE: [Card; 52] = {
+        let mut result = [ACE_OF_SPADES; 52];
+        let mut index = 0;
+        while index < result.len() {
+            result[index] = match Card::from_index(index as u8) {
+                Some(card) => card,
+                None => panic!("Invalid card index"),
+            };
+            index += 1;
+        }
+        result
+    };
+
+    #[test]
+    fn cards_can_be_built_in_const_context() {
+        assert_eq!(CARD_TABLE[51], ACE_OF_SPADES);
+        assert!(CARD_TABLE.iter().copied().eq(Card::all()));
+    }
+
+    #[test]
+    fn suit_rank_tuple_round_trips() {
+        for card in
+            [ACE_OF_SPADES, Card::new(Suit::Clubs, Rank::Two), Card::new(Suit::Hearts, Rank::Ten)]
+        {
+            let (suit, rank) = card.suit_rank_tuple();
+            assert_eq!((suit, rank), (card.suit, card.rank));
+            assert_eq!(Card::from(card.suit_rank_tuple()), card);
+        }
+    }
+
+    #[test]
+    fn seat_partnerships() {
+        use HandIdentifier::*;
+        assert_eq!(North.partnership(), Partnership::NorthSouth);
+        assert_eq!(South.partnership(), Partnership::NorthSouth);
+        assert_eq!(East.partnership(), Partnership::EastWest);
+        assert_eq!(West.partnership(), Partnership::EastWest);
+        assert_eq!(Partnership::NorthSouth.seats(), [North, South]);
+        assert_eq!(Partnership::EastWest.seats(), [East, West]);
+    }
+
+    #[test]
+    fn parse_cards_parses_full_hand() {
+        let cards = parse_cards("AS KS QS JS 10H 9H 8H 7D 6D 5D 4C 3C 2C").unwrap();
+        assert_eq!(cards.len(), 13);
+        assert_eq!(cards[0], ACE_OF_SPADES);
+        assert_eq!(cards[12], Card::new(Suit::Clubs, Rank::Two));
+    }
+
+    #[test]
+    fn parse_cards_reports_position_of_bad_token() {
+        assert_eq!(
+            parse_cards("AS KH XX 3D"),
+            Err(ParseCardError::InvalidToken {
+                index: 2,
+                error: Box::new(ParseCardError::UnknownSuit("X".to_string()))
+            })
+        );
+    }
+
+    #[test]
+    fn seat_and_player_display() {
+        let seats = all::<HandIdentifier>().map(|seat| seat.to_string()).collect::<Vec<_>>();
+        assert_eq!(seats, ["North", "East", "South", "West"]);
+        assert_eq!(PlayerName::User.to_string(), "User");
+        assert_eq!(PlayerName::Opponent.to_string(), "Opponent");
+    }
+
+    #[test]
+    fn strain_ordering_and_trump_suit() {
+        let strains = all::<Strain>().collect::<Vec<_>>();
+        assert!(strains.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(strains.last(), Some(&Strain::NoTrump));
+        for suit in all::<Suit>() {
+            assert_eq!(Strain::from(suit).trump_suit(), Some(suit));
+        }
+        assert_eq!(Strain::NoTrump.trump_suit(), None);
+    }
+
+    #[test]
+    fn strain_display() {
+        assert_eq!(Strain::Spades.to_string(), "♠");
+        assert_eq!(Strain::Clubs.to_string(), "♣");
+        assert_eq!(Strain::NoTrump.to_string(), "NT");
+    }
+
+    #[test]
+    fn honors_are_ten_through_ace() {
+        let honors = all::<Rank>().filter(Rank::is_honor).collect::<Vec<_>>();
+        assert_eq!(honors, [Rank::Ten, Rank::Jack, Rank::Queen, Rank::King, Rank::Ace]);
+    }
+
+    #[test]
+    fn ordinals_are_stable() {
+        let suits = all::<Suit>().map(|suit| (suit, suit.to_index())).collect::<Vec<_>>();
+        assert_eq!(
+            suits,
+            [(Suit::Clubs, 0), (Suit::Diamonds, 1), (Suit::Hearts, 2), (Suit::Spades, 3)]
+        );
+
+        let ranks = all::<Rank>().map(|rank| (rank, rank.to_index())).collect::<Vec<_>>();
+        assert_eq!(
+            ranks,
+            [
+                (Rank::Two, 0),
+                (Rank::Three, 1),
+                (Rank::Four, 2),
+                (Rank::Five, 3),
+                (Rank::Six, 4),
+                (Rank::Seven, 5),
+                (Rank::Eight, 6),
+                (Rank::Nine, 7),
+                (Rank::Ten, 8),
+                (Rank::Jack, 9),
+                (Rank::Queen, 10),
+                (Rank::King, 11),
+                (Rank::Ace, 12),
+            ]
+        );
+
+        let seats = all::<HandIdentifier>().map(|seat| (seat, seat.ordinal())).collect::<Vec<_>>();
+        assert_eq!(
+            seats,
+            [
+                (HandIdentifier::North, 0),
+                (HandIdentifier::East, 1),
+                (HandIdentifier::South, 2),
+                (HandIdentifier::West, 3),
+            ]
+        );
+
+        let players =
+            all::<PlayerName>().map(|player| (player, player.ordinal())).collect::<Vec<_>>();
+        assert_eq!(players, [(PlayerName::User, 0), (PlayerName::Opponent, 1)]);
+    }
+
+    #[test]
+    fn seat_and_player_from_str() {
+        for seat in all::<HandIdentifier>() {
+            assert_eq!(seat.to_string().parse(), Ok(seat));
+            assert_eq!(seat.to_string().to_uppercase().parse(), Ok(seat));
+        }
+        for player in all::<PlayerName>() {
+            assert_eq!(player.to_string().parse(), Ok(player));
+        }
+        assert_eq!("n".parse(), Ok(HandIdentifier::North));
+        assert_eq!("W".parse(), Ok(HandIdentifier::West));
+        assert_eq!(
+            "Northeast".parse::<HandIdentifier>(),
+            Err(ParseIdentifierError::UnknownHandIdentifier("Northeast".to_string()))
+        );
+        assert_eq!(
+            "Dealer".parse::<PlayerName>(),
+            Err(ParseIdentifierError::UnknownPlayerName("Dealer".to_string()))
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_card_from_fixed_bytes() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes = [3, 200, 17, 255, 0, 42];
+        let card = Card::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(card, Card::new(Suit::Spades, Rank::Two));
+        assert_eq!(Card::arbitrary(&mut Unstructured::new(&bytes)).unwrap(), card);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary_card_from_exhausted_input() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let mut empty = Unstructured::new(&[]);
+        assert_eq!(Card::arbitrary(&mut empty).unwrap(), Card::new(Suit::Clubs, Rank::Two));
+    }
+
+    #[test]
+    fn points_hcp_counts_honors() {
+        assert_eq!(Rank::Ace.points_hcp(), 4);
+        assert_eq!(Rank::King.points_hcp(), 3);
+        assert_eq!(Rank::Queen.points_hcp(), 2);
+        assert_eq!(Rank::Jack.points_hcp(), 1);
+        for rank in all::<Rank>().filter(|rank| *rank <= Rank::Ten) {
+            assert_eq!(rank.points_hcp(), 0, "{rank:?}");
+        }
+        assert_eq!(all::<Rank>().map(|rank| u32::from(rank.points_hcp())).sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn all_constants_match_enum_iterator_order() {
+        assert_eq!(Suit::ALL.len(), 4);
+        assert_eq!(Rank::ALL.len(), 13);
+        assert_eq!(HandIdentifier::ALL.len(), 4);
+        assert_eq!(Suit::ALL.to_vec(), all::<Suit>().collect::<Vec<_>>());
+        assert_eq!(Rank::ALL.to_vec(), all::<Rank>().collect::<Vec<_>>());
+        assert_eq!(HandIdentifier::ALL.to_vec(), all::<HandIdentifier>().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn suit_rotation() {
+        for suit in all::<Suit>() {
+            assert_eq!(suit.rotate(0), suit);
+            assert_eq!(suit.rotate(4), suit);
+        }
+        assert_eq!(Suit::Clubs.rotate(1), Suit::Diamonds);
+        assert_eq!(Suit::Hearts.rotate(1), Suit::Spades);
+        assert_eq!(Suit::Spades.rotate(1), Suit::Clubs);
+        assert_eq!(Suit::Diamonds.rotate(7), Suit::Clubs);
+
+        let card = Card::new(Suit::Hearts, Rank::Jack);
+        assert_eq!(card.rotate_suit(2), Card::new(Suit::Clubs, Rank::Jack));
+    }
+
+    #[test]
+    fn rank_category_boundary_at_ten() {
+        assert_eq!(Rank::Nine.category(), RankCategory::Spot);
+        assert_eq!(Rank::Ten.category(), RankCategory::Honor);
+        assert_eq!(Rank::Two.category(), RankCategory::Spot);
+        assert_eq!(Rank::Ace.category(), RankCategory::Honor);
+        assert_eq!(all::<Rank>().filter(|rank| rank.category() == RankCategory::Spot).count(), 8);
+    }
+
+    #[test]
+    fn prev_is_inverse_of_next() {
+        for seat in all::<HandIdentifier>() {
+            assert_eq!(seat.next().prev(), seat);
+            assert_eq!(seat.prev().next(), seat);
+        }
+        assert_eq!(HandIdentifier::North.prev(), HandIdentifier::West);
+    }
+
+    #[test]
+    fn all_rank_major_starts_with_twos() {
+        let cards = Card::all_rank_major().collect::<Vec<_>>();
+        assert_eq!(cards.len(), 52);
+        assert_eq!(
+            cards[..4],
+            all::<Suit>().map(|suit| Card::new(suit, Rank::Two)).collect::<Vec<_>>()[..]
+        );
+        assert_eq!(cards[51], Card::new(Suit::Spades, Rank::Ace));
+        assert!(cards
+            .windows(2)
+            .all(|pair| (pair[0].rank, pair[0].suit) < (pair[1].rank, pair[1].suit)));
+    }
+
+    #[test]
+    fn card_glyphs() {
+        assert_eq!(Card::new(Suit::Hearts, Rank::Ten).glyphs(), ("10".to_string(), '♥'));
+        assert_eq!(Card::new(Suit::Spades, Rank::Queen).glyphs(), ("Q".to_string(), '♠'));
+        assert_eq!(Card::new(Suit::Clubs, Rank::Two).glyphs(), ("2".to_string(), '♣'));
+    }
+
+    #[test]
+    fn card_from_str_accepts_words() {
+        assert_eq!("Ace of Hearts".parse(), Ok(Card::new(Suit::Hearts, Rank::Ace)));
+        assert_eq!("ten clubs".parse(), Ok(Card::new(Suit::Clubs, Rank::Ten)));
+        assert_eq!("  two OF diamonds ".parse(), Ok(Card::new(Suit::Diamonds, Rank::Two)));
+        for card in Card::all() {
+            assert_eq!(format!("{:?} of {:?}", card.rank, card.suit).parse(), Ok(card));
+        }
+    }
+
+    #[test]
+    fn card_from_str_rejects_unknown_words() {
+        assert_eq!(
+            "joker".parse::<Card>(),
+            Err(ParseCardError::InvalidLength("joker".to_string()))
+        );
+        assert!("joker of spades".parse::<Card>().is_err());
+        assert!("ace of stars".parse::<Card>().is_err());
+        assert!("ace in spades".parse::<Card>().is_err());
+    }
+
+    #[test]
+    fn same_rank_and_same_suit() {
+        let queen_of_hearts = Card::new(Suit::Hearts, Rank::Queen);
+        let queen_of_clubs = Card::new(Suit::Clubs, Rank::Queen);
+        let four_of_hearts = Card::new(Suit::Hearts, Rank::Four);
+        assert!(queen_of_hearts.same_rank(&queen_of_clubs));
+        assert!(!queen_of_hearts.same_rank(&four_of_hearts));
+        assert!(queen_of_hearts.same_suit(&four_of_hearts));
+        assert!(!queen_of_hearts.same_suit(&queen_of_clubs));
+        assert!(
+            queen_of_hearts.same_rank(&queen_of_hearts)
+                && queen_of_hearts.same_suit(&queen_of_hearts)
+        );
+    }
+
+    #[test]
+    fn rank_from_hcp() {
+        assert_eq!(Rank::from_hcp(4), Some(Rank::Ace));
+        assert_eq!(Rank::from_hcp(3), Some(Rank::King));
+        assert_eq!(Rank::from_hcp(2), Some(Rank::Queen));
+        assert_eq!(Rank::from_hcp(1), Some(Rank::Jack));
+        assert_eq!(Rank::from_hcp(0), None);
+        assert_eq!(Rank::from_hcp(5), None);
+        for points in 1..=4 {
+            assert_eq!(Rank::from_hcp(points).map(|rank| rank.points_hcp()), Some(points));
+        }
+    }
+
+    #[test]
+    fn for_all_cards_visits_each_card_once() {
+        let mut visited = Vec::new();
+        for_all_cards(|card| visited.push(card));
+        assert_eq!(visited.len(), 52);
+        assert_eq!(visited, Card::all().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn every_card_round_trips_through_index() {
+        let mut count = 0;
+        for_all_cards(|card| {
+            assert_eq!(Card::from_index(card.to_index()), Some(card));
+            assert_eq!(Card::from((card.suit, card.rank)), card);
+            count += 1;
+        });
+        assert_eq!(count, 52);
+    }
+
+    #[test]
+    fn suit_cycle_from_wraps_around() {
+        assert_eq!(
+            Suit::cycle_from(Suit::Hearts).collect::<Vec<_>>(),
+            [Suit::Hearts, Suit::Spades, Suit::Clubs, Suit::Diamonds]
+        );
+        assert_eq!(Suit::cycle_from(Suit::Clubs).collect::<Vec<_>>(), Suit::ALL);
+        for suit in all::<Suit>() {
+            assert_eq!(Suit::cycle_from(suit).next(), Some(suit));
+            assert_eq!(Suit::cycle_from(suit).count(), 4);
+        }
+    }
+
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn display_and_parsing_work_without_std() {
+        let card = Card::new(Suit::Hearts, Rank::Ten);
+        assert_eq!(format!("{card}"), "10♥");
+        assert_eq!(format!("{card:#}"), "10H");
+        assert_eq!("10H".parse(), Ok(card));
+        assert_eq!(format!("{}", HandIdentifier::West), "West");
+        assert_eq!(format!("{}", Strain::NoTrump), "NT");
+        assert_eq!(
+            all::<Suit>().map(|suit| suit.symbol()).collect::<Vec<_>>(),
+            ['♣', '♦', '♥', '♠']
+        );
+        assert_eq!(Card::all().count(), 52);
+    }
 }
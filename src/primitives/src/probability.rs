@@ -0,0 +1,54 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Estimates of how unseen cards are distributed between two hands.
+
+/// Returns the probability that a specific missing card is held by hand A,
+/// given that hand A has `vacant_a` unknown cards and hand B has `vacant_b`.
+///
+/// This is the vacant places estimate: every unknown card is equally likely to
+/// occupy any of the remaining vacant places, so the chance it lies with hand A
+/// is `vacant_a / (vacant_a + vacant_b)`. Returns 0.0 if neither hand has any
+/// vacant places.
+pub fn probability_in_hand(vacant_a: u8, vacant_b: u8) -> f64 {
+    let total = u16::from(vacant_a) + u16::from(vacant_b);
+    if total == 0 {
+        return 0.0;
+    }
+    f64::from(vacant_a) / f64::from(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_vacancies_are_even() {
+        assert_eq!(probability_in_hand(13, 13), 0.5);
+        assert_eq!(probability_in_hand(4, 4), 0.5);
+    }
+
+    #[test]
+    fn unequal_vacancies() {
+        assert_eq!(probability_in_hand(3, 1), 0.75);
+        assert_eq!(probability_in_hand(0, 5), 0.0);
+        assert_eq!(probability_in_hand(5, 0), 1.0);
+        assert_eq!(probability_in_hand(255, 255), 0.5);
+    }
+
+    #[test]
+    fn no_vacancies_is_zero() {
+        assert_eq!(probability_in_hand(0, 0), 0.0);
+    }
+}
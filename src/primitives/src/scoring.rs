@@ -0,0 +1,195 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::cmp::Ordering;
+
+use crate::{HandIdentifier, Partnership, Strain};
+
+/// A commitment by the declaring side to win a number of tricks.
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct Contract {
+    /// Number of tricks beyond six which the declaring side must win, from 1
+    /// to 7.
+    pub level: u8,
+    /// Trump strain for the round
+    pub trump: Strain,
+    pub declarer: HandIdentifier,
+}
+
+/// Returns the score for the declaring side of `contract` after it won
+/// `tricks_won` tricks.
+///
+/// This follows duplicate bridge scoring for an undoubled, non-vulnerable
+/// contract. Each trick bid and made scores 20 points in a minor suit, 30
+/// points in a major suit, and in no-trump 40 points for the first trick and 30
+/// for each subsequent trick. Making a contract adds a bonus of 300 points for
+/// a game (100 or more trick points) or 50 points for a part score, plus 500
+/// points for a small slam or 1000 points for a grand slam. Each overtrick
+/// scores 20 points in a minor suit and 30 points otherwise.
+///
+/// A contract which was not made instead scores -50 points per undertrick.
+pub fn score_contract(contract: &Contract, tricks_won: u8) -> i32 {
+    let result = contract_result(contract.level, tricks_won);
+    if result < 0 {
+        return 50 * i32::from(result);
+    }
+
+    let (trick_value, first_trick_bonus) = match contract.trump.trump_suit() {
+        None => (30, 10),
+        Some(suit) if suit.is_major() => (30, 0),
+        Some(_) => (20, 0),
+    };
+    let trick_points = trick_value * i32::from(contract.level) + first_trick_bonus;
+    let game_bonus = if trick_points >= 100 { 300 } else { 50 };
+    let slam_bonus = match contract.level {
+        6 => 500,
+        7 => 1000,
+        _ => 0,
+    };
+    let overtrick_points = trick_value * i32::from(result);
+    trick_points + game_bonus + slam_bonus + overtrick_points
+}
+
+/// Returns the number of tricks by which the declaring side made a contract at
+/// `contract_level` after winning `tricks_won` tricks. A contract at level N
+/// requires N + 6 tricks.
+///
+/// Positive values are overtricks, zero means the contract was made exactly,
+/// and negative values are undertricks.
+pub fn contract_result(contract_level: u8, tricks_won: u8) -> i8 {
+    tricks_won as i8 - (contract_level as i8 + 6)
+}
+
+/// Returns how many more tricks the declaring side needs to make a contract at
+/// `contract_level` after winning `tricks_so_far` tricks, or 0 if the contract
+/// has already been made.
+pub fn tricks_remaining_to_make(contract_level: u8, tricks_so_far: u8) -> u8 {
+    (contract_level + 6).saturating_sub(tricks_so_far)
+}
+
+/// Running point totals for each [Partnership] across the rounds of a match
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Default)]
+pub struct MatchScore {
+    north_south: i32,
+    east_west: i32,
+}
+
+impl MatchScore {
+    /// Adds the `points` scored by `partnership` in a round to its total
+    pub fn add_round(&mut self, partnership: Partnership, points: i32) {
+        match partnership {
+            Partnership::NorthSouth => self.north_south += points,
+            Partnership::EastWest => self.east_west += points,
+        }
+    }
+
+    /// Total points scored so far by `partnership`
+    pub fn total(&self, partnership: Partnership) -> i32 {
+        match partnership {
+            Partnership::NorthSouth => self.north_south,
+            Partnership::EastWest => self.east_west,
+        }
+    }
+
+    /// Returns the partnership with the higher total, or None if the totals
+    /// are tied
+    pub fn leader(&self) -> Option<Partnership> {
+        match self.north_south.cmp(&self.east_west) {
+            Ordering::Greater => Some(Partnership::NorthSouth),
+            Ordering::Less => Some(Partnership::EastWest),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contract(level: u8, trump: Strain) -> Contract {
+        Contract { level, trump, declarer: HandIdentifier::South }
+    }
+
+    #[test]
+    fn made_three_no_trump() {
+        assert_eq!(score_contract(&contract(3, Strain::NoTrump), 9), 400);
+        assert_eq!(score_contract(&contract(3, Strain::NoTrump), 10), 430);
+    }
+
+    #[test]
+    fn made_four_spades() {
+        assert_eq!(score_contract(&contract(4, Strain::Spades), 10), 420);
+    }
+
+    #[test]
+    fn part_score_in_a_minor() {
+        assert_eq!(score_contract(&contract(2, Strain::Clubs), 9), 40 + 50 + 20);
+    }
+
+    #[test]
+    fn slam_bonuses() {
+        assert_eq!(score_contract(&contract(6, Strain::Hearts), 12), 180 + 300 + 500);
+        assert_eq!(score_contract(&contract(7, Strain::NoTrump), 13), 220 + 300 + 1000);
+    }
+
+    #[test]
+    fn contract_down_two() {
+        assert_eq!(score_contract(&contract(4, Strain::Spades), 8), -100);
+    }
+
+    #[test]
+    fn contract_result_over_and_under() {
+        assert_eq!(contract_result(3, 9), 0);
+        assert_eq!(contract_result(3, 11), 2);
+        assert_eq!(contract_result(3, 7), -2);
+        assert_eq!(contract_result(7, 0), -13);
+    }
+
+    #[test]
+    fn tricks_remaining_to_make_contract() {
+        assert_eq!(tricks_remaining_to_make(4, 0), 10);
+        assert_eq!(tricks_remaining_to_make(4, 7), 3);
+        assert_eq!(tricks_remaining_to_make(1, 6), 1);
+        assert_eq!(tricks_remaining_to_make(3, 9), 0);
+        assert_eq!(tricks_remaining_to_make(3, 12), 0);
+    }
+
+    #[test]
+    fn match_score_accumulates_rounds() {
+        let mut score = MatchScore::default();
+        assert_eq!(score.leader(), None);
+
+        score.add_round(Partnership::NorthSouth, 420);
+        score.add_round(Partnership::EastWest, 110);
+        assert_eq!(score.total(Partnership::NorthSouth), 420);
+        assert_eq!(score.total(Partnership::EastWest), 110);
+        assert_eq!(score.leader(), Some(Partnership::NorthSouth));
+
+        score.add_round(Partnership::EastWest, 620);
+        assert_eq!(score.leader(), Some(Partnership::EastWest));
+    }
+
+    #[test]
+    fn match_score_tie_has_no_leader() {
+        let mut score = MatchScore::default();
+        score.add_round(Partnership::NorthSouth, 140);
+        score.add_round(Partnership::EastWest, 140);
+        assert_eq!(score.leader(), None);
+
+        score.add_round(Partnership::NorthSouth, -100);
+        score.add_round(Partnership::EastWest, -100);
+        assert_eq!(score.leader(), None);
+        assert_eq!(score.total(Partnership::NorthSouth), 40);
+    }
+}
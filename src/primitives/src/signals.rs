@@ -0,0 +1,76 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Interpretation of defensive carding signals.
+
+use crate::{Card, Rank};
+
+/// Attitude conveyed by a defender's choice of card
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub enum Signal {
+    /// The defender would like the suit continued
+    Encourage,
+    /// The defender would prefer a switch to another suit
+    Discourage,
+}
+
+/// Interprets `card` as a standard attitude signal, where a high spot card
+/// encourages and a low one discourages.
+///
+/// Cards of Seven or above encourage and lower cards discourage. The
+/// defender's lowest card in the suit, given by `lowest_in_suit`, is always
+/// treated as discouraging.
+pub fn attitude_signal(card: Card, lowest_in_suit: Rank) -> Signal {
+    if card.rank >= Rank::Seven && card.rank > lowest_in_suit {
+        Signal::Encourage
+    } else {
+        Signal::Discourage
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Suit;
+
+    #[test]
+    fn high_card_encourages() {
+        let nine_of_hearts = Card::new(Suit::Hearts, Rank::Nine);
+        assert_eq!(attitude_signal(nine_of_hearts, Rank::Three), Signal::Encourage);
+        assert_eq!(
+            attitude_signal(Card::new(Suit::Hearts, Rank::Seven), Rank::Two),
+            Signal::Encourage
+        );
+    }
+
+    #[test]
+    fn low_card_discourages() {
+        assert_eq!(
+            attitude_signal(Card::new(Suit::Clubs, Rank::Two), Rank::Two),
+            Signal::Discourage
+        );
+        assert_eq!(
+            attitude_signal(Card::new(Suit::Clubs, Rank::Six), Rank::Two),
+            Signal::Discourage
+        );
+    }
+
+    #[test]
+    fn lowest_card_in_suit_discourages() {
+        assert_eq!(
+            attitude_signal(Card::new(Suit::Spades, Rank::Eight), Rank::Eight),
+            Signal::Discourage
+        );
+    }
+}
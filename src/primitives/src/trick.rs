@@ -0,0 +1,653 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::Index;
+use core::{fmt, slice};
+
+use crate::card_set::CardSet;
+use crate::hand::Hand;
+use crate::{Card, HandIdentifier, Partnership, Rank, Strain, Suit};
+
+/// The cards played to a single trick, in the order they were played.
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub struct Trick {
+    plays: Vec<(HandIdentifier, Card)>,
+}
+
+impl Trick {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `card` being played to this trick by `seat`.
+    ///
+    /// Returns an error if four cards have already been played, or if `seat`
+    /// has already played to this trick.
+    pub fn play(&mut self, seat: HandIdentifier, card: Card) -> Result<(), TrickError> {
+        if self.is_complete() {
+            return Err(TrickError::Full);
+        }
+        if self.plays.iter().any(|(s, _)| *s == seat) {
+            return Err(TrickError::DuplicateSeat);
+        }
+        self.plays.push((seat, card));
+        Ok(())
+    }
+
+    /// Returns the cards played to this trick so far, in play order
+    pub fn plays(&self) -> &[(HandIdentifier, Card)] {
+        &self.plays
+    }
+
+    /// Returns the suit of the first card played to this trick, or None if no
+    /// cards have been played.
+    pub fn led_suit(&self) -> Option<Suit> {
+        self.plays.first().map(|(_, card)| card.suit)
+    }
+
+    /// Returns true once all four seats have played to this trick
+    pub fn is_complete(&self) -> bool {
+        self.plays.len() == 4
+    }
+
+    /// Returns the seat which is currently winning this trick, as determined
+    /// by [winning_card], or [TrickError::Empty] if no cards have been played.
+    pub fn winner(&self, trump: Strain) -> Result<HandIdentifier, TrickError> {
+        winning_card(&self.plays, self.led_suit().ok_or(TrickError::Empty)?, trump)
+    }
+}
+
+impl Index<usize> for Trick {
+    type Output = (HandIdentifier, Card);
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.plays[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a Trick {
+    type IntoIter = slice::Iter<'a, (HandIdentifier, Card)>;
+    type Item = &'a (HandIdentifier, Card);
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.plays.iter()
+    }
+}
+
+/// Error returned when a trick's plays are not valid
+#[derive(PartialEq, Eq, Debug, Copy, Clone)]
+pub enum TrickError {
+    /// No cards have been played to the trick
+    Empty,
+    /// A seat played more than one card to the trick
+    DuplicateSeat,
+    /// The suit given as led does not match the first card played
+    OffLeadMismatch,
+    /// All four cards have already been played to the trick
+    Full,
+    /// The trick was not led by the seat whose turn it was to lead
+    WrongLeader,
+}
+
+impl fmt::Display for TrickError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TrickError::Empty => "No cards have been played to this trick",
+                TrickError::DuplicateSeat => "A seat played more than once to this trick",
+                TrickError::OffLeadMismatch => "The led suit does not match the first card played",
+                TrickError::Full => "All four cards have already been played to this trick",
+                TrickError::WrongLeader => "This trick was led out of turn",
+            }
+        )
+    }
+}
+
+impl core::error::Error for TrickError {}
+
+/// Counts the tricks won by each [Partnership] during a round
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone, Default)]
+pub struct TrickTally {
+    north_south: u8,
+    east_west: u8,
+}
+
+impl TrickTally {
+    /// Records a trick won by `winner`, crediting that seat's partnership
+    pub fn record(&mut self, winner: HandIdentifier) {
+        match winner.partnership() {
+            Partnership::NorthSouth => self.north_south += 1,
+            Partnership::EastWest => self.east_west += 1,
+        }
+    }
+
+    /// Number of tricks won so far by `partnership`
+    pub fn tricks(&self, partnership: Partnership) -> u8 {
+        match partnership {
+            Partnership::NorthSouth => self.north_south,
+            Partnership::EastWest => self.east_west,
+        }
+    }
+}
+
+/// The tricks played during a round, in order
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Default)]
+pub struct TrickHistory {
+    tricks: Vec<Trick>,
+}
+
+impl TrickHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the next trick played
+    pub fn push(&mut self, trick: Trick) {
+        self.tricks.push(trick);
+    }
+
+    /// Number of tricks recorded
+    pub fn len(&self) -> usize {
+        self.tricks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tricks.is_empty()
+    }
+
+    /// Returns the tricks recorded so far, in play order
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    /// Replays the recorded tricks and returns the winner of each in turn.
+    ///
+    /// `first_leader` leads the first trick and the winner of each trick leads
+    /// the next. Returns [TrickError::WrongLeader] if a trick was not led by
+    /// the expected seat, or any error from [Trick::winner].
+    pub fn replay(
+        &self,
+        trump: Strain,
+        first_leader: HandIdentifier,
+    ) -> Result<Vec<HandIdentifier>, TrickError> {
+        let mut leader = first_leader;
+        let mut result = vec![];
+        for trick in &self.tricks {
+            match trick.plays().first() {
+                Some((seat, _)) if *seat != leader => return Err(TrickError::WrongLeader),
+                _ => {}
+            }
+            leader = trick.winner(trump)?;
+            result.push(leader);
+        }
+        Ok(result)
+    }
+}
+
+/// Returns the seat which leads the first trick of a round, the position to
+/// the left of the `declarer`.
+pub fn opening_leader(declarer: HandIdentifier) -> HandIdentifier {
+    declarer.next()
+}
+
+/// Returns the suit led to a trick given its plays in any order, i.e. the suit
+/// of the card played by `leader`, or None if `leader` has not yet played.
+pub fn infer_led_suit(plays: &[(HandIdentifier, Card)], leader: HandIdentifier) -> Option<Suit> {
+    plays.iter().find(|(seat, _)| *seat == leader).map(|(_, card)| card.suit)
+}
+
+/// Returns the seat which played the winning card of a trick.
+///
+/// A card of the trump suit for the `trump` strain beats any card which is not
+/// a trump, and a card of the `led` suit beats any card of another non-trump
+/// suit. In no-trump, the highest card of the led suit wins. Between two
+/// cards of the same suit, the higher rank wins. This means a card which is
+/// neither a trump nor of the led suit can never win the trick.
+///
+/// Returns an error if `cards` is empty, if any seat appears more than once,
+/// or if the first card is not of the `led` suit.
+pub fn winning_card(
+    cards: &[(HandIdentifier, Card)],
+    led: Suit,
+    trump: Strain,
+) -> Result<HandIdentifier, TrickError> {
+    let (_, first) = cards.first().ok_or(TrickError::Empty)?;
+    if first.suit != led {
+        return Err(TrickError::OffLeadMismatch);
+    }
+    for (i, (seat, _)) in cards.iter().enumerate() {
+        if cards[..i].iter().any(|(s, _)| s == seat) {
+            return Err(TrickError::DuplicateSeat);
+        }
+    }
+
+    Ok(cards
+        .iter()
+        .max_by(|(_, a), (_, b)| compare_in_trick(*a, *b, led, trump))
+        .expect("Trick is not empty")
+        .0)
+}
+
+/// Returns a value representing the strength of a card when played to a trick,
+/// such that the card with the highest strength wins the trick.
+///
+/// Trumps are stronger than cards of the `led` suit, which are stronger than
+/// cards of any other suit. Within each of these groups strength increases
+/// with rank.
+pub fn rank_strength(rank: Rank, suit: Suit, led: Suit, trump: Strain) -> u16 {
+    let priority = if trump.is_trump(suit) {
+        2
+    } else if suit == led {
+        1
+    } else {
+        0
+    };
+    priority * 13 + u16::from(rank.to_index())
+}
+
+/// Compares two cards played to the same trick, such that the greater card
+/// would win the trick.
+///
+/// Cards are first compared by [rank_strength], then by suit. This gives a
+/// total order, so that cards of two different suits which are neither trumps
+/// nor of the `led` suit are still ordered deterministically.
+pub fn compare_in_trick(a: Card, b: Card, led: Suit, trump: Strain) -> Ordering {
+    rank_strength(a.rank, a.suit, led, trump)
+        .cmp(&rank_strength(b.rank, b.suit, led, trump))
+        .then_with(|| a.suit.cmp(&b.suit))
+}
+
+/// A card played to a trick, ordered by [compare_in_trick] so that sorting a
+/// trick's cards places the winning card last, and a
+/// [alloc::collections::BinaryHeap] of a trick's cards pops the winning card
+/// first.
+///
+/// Only cards which share the same `led` suit and `trump` strain are
+/// meaningfully comparable. Equality and hashing consider only the card, to
+/// agree with this ordering.
+#[derive(Debug, Copy, Clone)]
+pub struct TrickCard {
+    pub card: Card,
+    pub led: Suit,
+    pub trump: Strain,
+}
+
+impl PartialEq for TrickCard {
+    fn eq(&self, other: &Self) -> bool {
+        self.card == other.card
+    }
+}
+
+impl Eq for TrickCard {}
+
+impl Hash for TrickCard {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.card.hash(state);
+    }
+}
+
+impl PartialOrd for TrickCard {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TrickCard {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare_in_trick(self.card, other.card, self.led, self.trump)
+    }
+}
+
+/// Returns the cards in `hand` which may legally be played to a trick. `led`
+/// is the suit which was led to the trick, or None if this hand is leading.
+///
+/// A hand holding any cards of the led suit must follow suit, otherwise it may
+/// play any card.
+pub fn legal_plays(hand: &Hand, led: Option<Suit>) -> CardSet {
+    let all = hand.iter().collect::<CardSet>();
+    let following = hand.iter().filter(|card| Some(card.suit) == led).collect::<CardSet>();
+    if following.is_empty() {
+        all
+    } else {
+        following
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use enum_iterator::all;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::SeedableRng;
+
+    use super::*;
+    use crate::parse_cards;
+    use crate::HandIdentifier::{East, North, South, West};
+
+    fn plays(cards: [(HandIdentifier, &str); 4]) -> [(HandIdentifier, Card); 4] {
+        cards.map(|(seat, card)| (seat, card.parse().unwrap()))
+    }
+
+    #[test]
+    fn trump_beats_led_suit() {
+        let trick = plays([(North, "AH"), (East, "2S"), (South, "KH"), (West, "3H")]);
+        assert_eq!(winning_card(&trick, Suit::Hearts, Strain::Spades), Ok(East));
+    }
+
+    #[test]
+    fn higher_trump_overtrumps() {
+        let trick = plays([(North, "AH"), (East, "2S"), (South, "5S"), (West, "3H")]);
+        assert_eq!(winning_card(&trick, Suit::Hearts, Strain::Spades), Ok(South));
+    }
+
+    #[test]
+    fn highest_card_of_led_suit_wins_without_trumps() {
+        let trick = plays([(North, "10H"), (East, "AD"), (South, "QH"), (West, "3H")]);
+        assert_eq!(winning_card(&trick, Suit::Hearts, Strain::Spades), Ok(South));
+        assert_eq!(winning_card(&trick, Suit::Hearts, Strain::NoTrump), Ok(South));
+    }
+
+    #[test]
+    fn off_suit_card_never_wins() {
+        let trick = plays([(North, "2H"), (East, "AC"), (South, "AD"), (West, "AS")]);
+        assert_eq!(winning_card(&trick, Suit::Hearts, Strain::NoTrump), Ok(North));
+    }
+
+    #[test]
+    fn empty_trick_is_an_error() {
+        assert_eq!(winning_card(&[], Suit::Hearts, Strain::NoTrump), Err(TrickError::Empty));
+    }
+
+    #[test]
+    fn legal_plays_must_follow_suit() {
+        let hand = parse_cards("AS 3H 9H KD").unwrap().into_iter().collect::<Hand>();
+        let expected = parse_cards("3H 9H").unwrap().into_iter().collect::<CardSet>();
+        assert_eq!(legal_plays(&hand, Some(Suit::Hearts)), expected);
+    }
+
+    #[test]
+    fn legal_plays_when_void_in_led_suit() {
+        let hand = parse_cards("AS 3H 9H KD").unwrap().into_iter().collect::<Hand>();
+        assert_eq!(legal_plays(&hand, Some(Suit::Clubs)).len(), 4);
+    }
+
+    #[test]
+    fn legal_plays_when_leading() {
+        let hand = parse_cards("AS 3H 9H KD").unwrap().into_iter().collect::<Hand>();
+        assert_eq!(legal_plays(&hand, None), hand.iter().collect());
+    }
+
+    #[test]
+    fn rank_strength_orders_trump_led_and_off_suit() {
+        let strength = |rank, suit| rank_strength(rank, suit, Suit::Hearts, Strain::Spades);
+        assert!(strength(Rank::Two, Suit::Spades) > strength(Rank::Ace, Suit::Hearts));
+        assert!(strength(Rank::Two, Suit::Hearts) > strength(Rank::Ace, Suit::Clubs));
+        assert!(strength(Rank::King, Suit::Hearts) > strength(Rank::Queen, Suit::Hearts));
+    }
+
+    #[test]
+    fn winning_card_and_rank_strength_agree_with_simple_oracle() {
+        // Highest trump if any trump was played, otherwise highest card of the
+        // led suit.
+        let oracle = |trick: &[(HandIdentifier, Card)], led: Suit, trump: Strain| {
+            let trumps = trick.iter().filter(|(_, card)| trump.trump_suit() == Some(card.suit));
+            let followers = trick.iter().filter(|(_, card)| card.suit == led);
+            let (seat, _) = trumps
+                .max_by_key(|(_, card)| card.rank)
+                .or_else(|| followers.max_by_key(|(_, card)| card.rank))
+                .unwrap();
+            *seat
+        };
+
+        let mut rng = StdRng::seed_from_u64(34);
+        let strains = all::<Strain>().collect::<Vec<_>>();
+        let mut deck = Card::all().collect::<Vec<_>>();
+        for _ in 0..500 {
+            deck.shuffle(&mut rng);
+            let leader = *HandIdentifier::ALL.choose(&mut rng).unwrap();
+            let trump = *strains.choose(&mut rng).unwrap();
+            let trick =
+                HandIdentifier::iter_from(leader).zip(deck.iter().copied()).collect::<Vec<_>>();
+            let led = trick[0].1.suit;
+            let expected = oracle(&trick, led, trump);
+            assert_eq!(winning_card(&trick, led, trump), Ok(expected));
+
+            let strength = |(_, card): &(HandIdentifier, Card)| {
+                rank_strength(card.rank, card.suit, led, trump)
+            };
+            let winner = trick.iter().find(|(seat, _)| *seat == expected).unwrap();
+            assert!(trick
+                .iter()
+                .filter(|play| play != &winner)
+                .all(|play| strength(play) < strength(winner)));
+        }
+    }
+
+    fn full_trick(cards: [(HandIdentifier, &str); 4]) -> Trick {
+        let mut trick = Trick::new();
+        for (seat, card) in plays(cards) {
+            trick.play(seat, card).unwrap();
+        }
+        trick
+    }
+
+    #[test]
+    fn trick_winner_with_and_without_trump() {
+        let trick = full_trick([(West, "QD"), (North, "AD"), (East, "3C"), (South, "5D")]);
+        assert!(trick.is_complete());
+        assert_eq!(trick.led_suit(), Some(Suit::Diamonds));
+        assert_eq!(trick.winner(Strain::NoTrump), Ok(North));
+        assert_eq!(trick.winner(Strain::Clubs), Ok(East));
+    }
+
+    #[test]
+    fn trick_indexing_and_iteration() {
+        let trick = full_trick([(West, "QD"), (North, "AD"), (East, "3C"), (South, "5D")]);
+        assert_eq!(trick[1], (North, "AD".parse().unwrap()));
+        assert_eq!(
+            (&trick).into_iter().map(|(seat, _)| *seat).collect::<Vec<_>>(),
+            [West, North, East, South]
+        );
+        assert_eq!(trick.plays().len(), 4);
+    }
+
+    #[test]
+    fn playing_to_full_trick_is_an_error() {
+        let mut trick = full_trick([(West, "QD"), (North, "AD"), (East, "3C"), (South, "5D")]);
+        assert_eq!(trick.play(West, "2H".parse().unwrap()), Err(TrickError::Full));
+        assert_eq!(trick.plays().len(), 4);
+    }
+
+    #[test]
+    fn opening_leader_sits_left_of_declarer() {
+        assert_eq!(opening_leader(North), East);
+        assert_eq!(opening_leader(East), South);
+        assert_eq!(opening_leader(South), West);
+        assert_eq!(opening_leader(West), North);
+    }
+
+    fn trick_cards(cards: &str, led: Suit, trump: Strain) -> Vec<TrickCard> {
+        parse_cards(cards).unwrap().into_iter().map(|card| TrickCard { card, led, trump }).collect()
+    }
+
+    #[test]
+    fn sorting_places_trump_winner_last() {
+        let mut cards = trick_cards("AH 3S KH 2C", Suit::Hearts, Strain::Spades);
+        cards.sort();
+        assert_eq!(cards.last().unwrap().card, "3S".parse().unwrap());
+    }
+
+    #[test]
+    fn sorting_places_highest_led_card_last_in_no_trump() {
+        let mut cards = trick_cards("10H AS KH 2C", Suit::Hearts, Strain::NoTrump);
+        cards.sort();
+        assert_eq!(cards.last().unwrap().card, "KH".parse().unwrap());
+    }
+
+    #[test]
+    fn trick_tally_counts_by_partnership() {
+        let mut tally = TrickTally::default();
+        let winners =
+            [North, East, South, South, West, North, East, North, South, West, North, North, East];
+        for winner in winners {
+            tally.record(winner);
+        }
+        assert_eq!(tally.tricks(Partnership::NorthSouth), 8);
+        assert_eq!(tally.tricks(Partnership::EastWest), 5);
+        assert_eq!(tally.tricks(Partnership::NorthSouth) + tally.tricks(Partnership::EastWest), 13);
+    }
+
+    #[test]
+    fn compare_in_trick_ranks_trump_then_led_then_other() {
+        let card = |token: &str| token.parse::<Card>().unwrap();
+        let led = Suit::Diamonds;
+        let trump = Strain::Hearts;
+        let compare = |a: &str, b: &str| compare_in_trick(card(a), card(b), led, trump);
+
+        assert_eq!(compare("2H", "AD"), Ordering::Greater);
+        assert_eq!(compare("2D", "AS"), Ordering::Greater);
+        assert_eq!(compare("2H", "AC"), Ordering::Greater);
+        assert_eq!(compare("3H", "2H"), Ordering::Greater);
+        assert_eq!(compare("3D", "KD"), Ordering::Less);
+        assert_eq!(compare("9S", "8C"), Ordering::Greater);
+        assert_eq!(compare("8S", "8C"), Ordering::Greater);
+        assert_eq!(compare("QD", "QD"), Ordering::Equal);
+    }
+
+    #[test]
+    fn compare_in_trick_without_trumps() {
+        let card = |token: &str| token.parse::<Card>().unwrap();
+        let compare =
+            |a: &str, b: &str| compare_in_trick(card(a), card(b), Suit::Clubs, Strain::NoTrump);
+        assert_eq!(compare("2C", "AS"), Ordering::Greater);
+        assert_eq!(compare("AH", "2C"), Ordering::Less);
+        assert_eq!(compare("JC", "10C"), Ordering::Greater);
+    }
+
+    #[test]
+    fn infer_led_suit_from_leader() {
+        let cards = plays([(West, "QD"), (North, "AD"), (East, "3C"), (South, "5D")]);
+        assert_eq!(infer_led_suit(&cards, West), Some(Suit::Diamonds));
+        assert_eq!(infer_led_suit(&cards, East), Some(Suit::Clubs));
+        assert_eq!(infer_led_suit(&cards[1..], West), None);
+        assert_eq!(infer_led_suit(&[], North), None);
+    }
+
+    #[test]
+    fn no_trump_trick_resolves_by_led_suit_and_rank() {
+        let trick = plays([(North, "9C"), (East, "AS"), (South, "JC"), (West, "KH")]);
+        assert_eq!(winning_card(&trick, Suit::Clubs, Strain::NoTrump), Ok(South));
+        assert_eq!(winning_card(&trick, Suit::Clubs, Strain::Hearts), Ok(West));
+        assert_eq!(winning_card(&trick, Suit::Clubs, Strain::Spades), Ok(East));
+        assert_eq!(winning_card(&trick, Suit::Clubs, Strain::Clubs), Ok(South));
+
+        assert!(Strain::NoTrump.is_no_trump());
+        assert!(all::<Suit>().all(|suit| !Strain::NoTrump.is_trump(suit)));
+        assert!(!Strain::Clubs.is_no_trump());
+    }
+
+    #[test]
+    fn empty_trick_has_no_winner() {
+        assert_eq!(Trick::new().winner(Strain::Spades), Err(TrickError::Empty));
+        assert_eq!(Trick::new().winner(Strain::NoTrump), Err(TrickError::Empty));
+    }
+
+    #[test]
+    fn duplicate_seat_is_an_error() {
+        let trick = plays([(North, "9C"), (East, "AS"), (North, "JC"), (West, "KH")]);
+        assert_eq!(
+            winning_card(&trick, Suit::Clubs, Strain::NoTrump),
+            Err(TrickError::DuplicateSeat)
+        );
+
+        let mut trick = Trick::new();
+        trick.play(South, "4D".parse().unwrap()).unwrap();
+        assert_eq!(trick.play(South, "5D".parse().unwrap()), Err(TrickError::DuplicateSeat));
+        assert_eq!(trick.plays().len(), 1);
+    }
+
+    #[test]
+    fn led_suit_must_match_first_card() {
+        let trick = plays([(North, "9C"), (East, "AS"), (South, "JC"), (West, "KH")]);
+        assert_eq!(
+            winning_card(&trick, Suit::Spades, Strain::NoTrump),
+            Err(TrickError::OffLeadMismatch)
+        );
+        assert_eq!(
+            winning_card(&trick[1..], Suit::Clubs, Strain::NoTrump),
+            Err(TrickError::OffLeadMismatch)
+        );
+    }
+
+    #[test]
+    fn binary_heap_pops_trick_winner_first() {
+        let mut heap = trick_cards("AH 3S KH 2C", Suit::Hearts, Strain::Spades)
+            .into_iter()
+            .collect::<alloc::collections::BinaryHeap<_>>();
+        let order = core::iter::from_fn(|| heap.pop()).map(|card| card.card).collect::<Vec<_>>();
+        assert_eq!(order, parse_cards("3S AH KH 2C").unwrap());
+
+        let mut heap = trick_cards("4D JD 10C AS", Suit::Diamonds, Strain::NoTrump)
+            .into_iter()
+            .collect::<alloc::collections::BinaryHeap<_>>();
+        assert_eq!(heap.pop().map(|card| card.card), Some("JD".parse().unwrap()));
+    }
+
+    #[test]
+    fn trick_card_equality_agrees_with_ordering() {
+        let cards = trick_cards("AH 3S KH 2C 7D", Suit::Hearts, Strain::Spades);
+        for a in &cards {
+            for b in &cards {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{a:?} {b:?}");
+                assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+            }
+        }
+    }
+
+    #[test]
+    fn replay_passes_lead_to_each_winner() {
+        let mut history = TrickHistory::new();
+        history.push(full_trick([(East, "2S"), (South, "AS"), (West, "3S"), (North, "4S")]));
+        history.push(full_trick([(South, "KH"), (West, "AH"), (North, "2H"), (East, "3H")]));
+        history.push(full_trick([(West, "5D"), (North, "6D"), (East, "2C"), (South, "7D")]));
+        assert_eq!(history.len(), 3);
+        assert_eq!(history.replay(Strain::NoTrump, East), Ok(vec![South, West, South]));
+        assert_eq!(history.replay(Strain::Clubs, East), Ok(vec![South, West, East]));
+        assert_eq!(TrickHistory::new().replay(Strain::NoTrump, North), Ok(vec![]));
+    }
+
+    #[test]
+    fn replay_rejects_trick_led_out_of_turn() {
+        let mut history = TrickHistory::new();
+        history.push(full_trick([(East, "2S"), (South, "AS"), (West, "3S"), (North, "4S")]));
+        assert_eq!(history.replay(Strain::NoTrump, North), Err(TrickError::WrongLeader));
+
+        history.push(full_trick([(North, "KH"), (East, "AH"), (South, "2H"), (West, "3H")]));
+        assert_eq!(history.replay(Strain::NoTrump, East), Err(TrickError::WrongLeader));
+    }
+
+    #[test]
+    fn replay_reports_empty_trick() {
+        let mut history = TrickHistory::new();
+        history.push(full_trick([(East, "2S"), (South, "AS"), (West, "3S"), (North, "4S")]));
+        history.push(Trick::new());
+        assert_eq!(history.replay(Strain::NoTrump, East), Err(TrickError::Empty));
+    }
+}
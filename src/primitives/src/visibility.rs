@@ -0,0 +1,69 @@
+// Copyright © 2024-present
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+//    https://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+
+use crate::{HandIdentifier, PlayerName};
+
+/// Returns the hands whose cards `viewer` is allowed to see.
+///
+/// A player can always see their own [PlayerName::primary_hand]. Once the
+/// auction has determined a `declarer`, the declaring player can also see
+/// their dummy, since they will be playing its cards. The defending player can
+/// only see the dummy once `dummy_revealed` is true.
+pub fn visible_hands(
+    viewer: PlayerName,
+    declarer: Option<HandIdentifier>,
+    dummy_revealed: bool,
+) -> HashSet<HandIdentifier> {
+    let mut result = HashSet::from([viewer.primary_hand()]);
+    if let Some(declarer) = declarer {
+        if viewer.is_declaring_side(declarer) {
+            result.extend([declarer, HandIdentifier::dummy_of(declarer)]);
+        } else if dummy_revealed {
+            result.insert(HandIdentifier::dummy_of(declarer));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::HandIdentifier::{East, North, South, West};
+
+    #[test]
+    fn only_primary_hand_before_auction() {
+        assert_eq!(visible_hands(PlayerName::User, None, false), HashSet::from([South]));
+        assert_eq!(visible_hands(PlayerName::Opponent, None, true), HashSet::from([West]));
+    }
+
+    #[test]
+    fn user_as_declarer_sees_dummy() {
+        assert_eq!(
+            visible_hands(PlayerName::User, Some(South), false),
+            HashSet::from([South, North])
+        );
+        assert_eq!(
+            visible_hands(PlayerName::User, Some(North), false),
+            HashSet::from([South, North])
+        );
+    }
+
+    #[test]
+    fn user_as_defender_sees_dummy_once_revealed() {
+        assert_eq!(visible_hands(PlayerName::User, Some(West), false), HashSet::from([South]));
+        assert_eq!(visible_hands(PlayerName::User, Some(West), true), HashSet::from([South, East]));
+    }
+}